@@ -0,0 +1,273 @@
+//! The derive macros of the `iterators-collection` crate. They are re-exported by `iterators-collection` when its `derive` feature is enabled, which is the prefered way to use them
+//! 
+//! The input is parsed by hand with the `proc_macro` API only, so this crate has no dependency
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Implements `ResettableIterator` for a struct by forwarding `reset` to its field marked `#[resettable_parent]`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::ResettableIterator;
+/// use iterators_collection::filter::Exclude;
+/// use iterators_collection::reset::SliceResetIter;
+/// 
+/// #[derive(ResettableIterator)]
+/// struct Doubled<I: Iterator<Item = i32>> {
+///     #[resettable_parent]
+///     inner: I,
+/// }
+/// 
+/// impl<I: Iterator<Item = i32>> Iterator for Doubled<I> {
+///     type Item = i32;
+/// 
+///     fn next(&mut self) -> Option<i32> {
+///         self.inner.next().map(|x| x * 2)
+///     }
+/// }
+/// 
+/// let mut iter = Doubled { inner: Exclude::with_blacklist(SliceResetIter::new(&[1, 2, 3]).resettable_map(|x| *x), vec![2]) };
+/// assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![2, 6]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.next(), Some(2));
+/// ```
+/// 
+/// # Notes
+/// The struct must implement `Iterator` itself. The generated implementation requires the type of the marked field to implement `ResettableIterator`, and exactly one field must be marked
+/// 
+/// ```compile_fail
+/// use iterators_collection::ResettableIterator;
+/// 
+/// // No field is marked
+/// #[derive(ResettableIterator)]
+/// struct Wrapper<I>(I);
+/// ```
+#[proc_macro_derive(ResettableIterator, attributes(resettable_parent))]
+pub fn derive_resettable_iterator(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(output) => output,
+        Err(message) => format!("compile_error!({:?});", message).parse().unwrap(),
+    }
+}
+
+/// A parsed struct, every part being kept as source code
+struct Input {
+    name: String,
+    impl_generics: Vec<String>,
+    type_generics: Vec<String>,
+    where_clause: String,
+    field: String,
+    field_type: String,
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, String> {
+    let input = parse(input)?;
+
+    let mut predicates = input.where_clause.trim().trim_end_matches(',').to_string();
+    if !predicates.is_empty() {
+        predicates.push(',');
+    }
+
+    let output = format!(
+        "impl<{impl_generics}> ::iterators_collection::ResettableIterator for {name}<{type_generics}> \
+         where {predicates} {field_type}: ::iterators_collection::ResettableIterator {{ \
+             fn reset(&mut self) {{ ::iterators_collection::ResettableIterator::reset(&mut self.{field}); }} \
+         }}",
+        impl_generics = input.impl_generics.join(", "),
+        name = input.name,
+        type_generics = input.type_generics.join(", "),
+        predicates = predicates,
+        field_type = input.field_type,
+        field = input.field,
+    );
+
+    output.parse().map_err(|_| "the derived implementation could not be generated".to_string())
+}
+
+fn parse(input: TokenStream) -> Result<Input, String> {
+    let tokens = input.into_iter().collect::<Vec<TokenTree>>();
+    let mut cur = 0;
+
+    // Skips the attributes and the visibility up to the `struct` keyword
+    loop {
+        match tokens.get(cur) {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => break,
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "enum" || ident.to_string() == "union" => {
+                return Err("ResettableIterator can only be derived for structs".to_string())
+            },
+            Some(_) => cur += 1,
+            None => return Err("expected a struct".to_string()),
+        }
+    }
+
+    let name = match tokens.get(cur + 1) {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected the name of the struct".to_string()),
+    };
+    cur += 2;
+
+    // The generic parameters, between the outer angle brackets
+    let mut generics = Vec::new();
+    if is_punct(tokens.get(cur), '<') {
+        let mut depth = 1;
+        cur += 1;
+
+        loop {
+            let token = tokens.get(cur).ok_or("unclosed generic parameters")?;
+            if is_punct(Some(token), '<') {
+                depth += 1;
+            } else if is_punct(Some(token), '>') && !is_arrow(&tokens, cur) {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+
+            generics.push(token.clone());
+            cur += 1;
+        }
+        cur += 1;
+    }
+
+    let (impl_generics, type_generics) = split_generics(&generics);
+
+    // The where clause, before the body of a struct with named fields or after the body of a tuple struct
+    let mut where_clause = Vec::new();
+    let mut body = None;
+    let mut in_where = false;
+    while let Some(token) = tokens.get(cur) {
+        match token {
+            TokenTree::Group(group) if body.is_none() && (group.delimiter() == Delimiter::Brace || (!in_where && group.delimiter() == Delimiter::Parenthesis)) => {
+                body = Some(group.clone());
+            },
+            TokenTree::Ident(ident) if ident.to_string() == "where" => in_where = true,
+            TokenTree::Punct(punct) if punct.as_char() == ';' => {},
+            _ => where_clause.push(token.clone()),
+        }
+        cur += 1;
+    }
+
+    let body = body.ok_or("ResettableIterator can't be derived for unit structs")?;
+    let tuple = body.delimiter() == Delimiter::Parenthesis;
+
+    let mut marked = Vec::new();
+    for (index, field) in split_top_level(&body.stream().into_iter().collect::<Vec<TokenTree>>()).into_iter().enumerate() {
+        let mut cur = 0;
+        let mut is_marked = false;
+
+        // The attributes of the field
+        while is_punct(field.get(cur), '#') {
+            if let Some(TokenTree::Group(group)) = field.get(cur + 1) {
+                is_marked |= group.stream().to_string().trim() == "resettable_parent";
+            }
+            cur += 2;
+        }
+
+        // The visibility of the field
+        if let Some(TokenTree::Ident(ident)) = field.get(cur) {
+            if ident.to_string() == "pub" {
+                cur += 1;
+                if let Some(TokenTree::Group(group)) = field.get(cur) {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        cur += 1;
+                    }
+                }
+            }
+        }
+
+        let field_name = if tuple {
+            index.to_string()
+        } else {
+            let name = field.get(cur).map(|token| token.to_string()).ok_or("expected the name of a field")?;
+            cur += 2; // Skips the colon too
+            name
+        };
+
+        if is_marked {
+            marked.push((field_name, stringify(&field[cur.min(field.len())..])));
+        }
+    }
+
+    if marked.len() != 1 {
+        return Err("exactly one field must be marked #[resettable_parent]".to_string());
+    }
+    let (field, field_type) = marked.pop().unwrap();
+
+    Ok(Input {
+        name,
+        impl_generics,
+        type_generics,
+        where_clause: stringify(&where_clause),
+        field,
+        field_type,
+    })
+}
+
+/// Splits the generic parameters into the parameters of the `impl`, without their default values, and the arguments of the type
+fn split_generics(generics: &[TokenTree]) -> (Vec<String>, Vec<String>) {
+    let mut impl_generics = Vec::new();
+    let mut type_generics = Vec::new();
+
+    for param in split_top_level(generics) {
+        // Drops the default value, which follows the first `=` not between angle brackets
+        let mut depth = 0;
+        let end = param.iter().enumerate().position(|(i, token)| {
+            if is_punct(Some(token), '<') {
+                depth += 1;
+            } else if is_punct(Some(token), '>') && !is_arrow(&param, i) {
+                depth -= 1;
+            }
+
+            depth == 0 && is_punct(Some(token), '=')
+        }).unwrap_or(param.len());
+        let param = &param[..end];
+
+        let name = match param.first() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '\'' => stringify(&param[..2]),
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "const" => param[1].to_string(),
+            Some(token) => token.to_string(),
+            None => continue,
+        };
+
+        impl_generics.push(stringify(param));
+        type_generics.push(name);
+    }
+
+    (impl_generics, type_generics)
+}
+
+/// Splits a list of tokens on the commas which are not between angle brackets
+fn split_top_level(tokens: &[TokenTree]) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    let mut depth = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        if is_punct(Some(token), '<') {
+            depth += 1;
+        } else if is_punct(Some(token), '>') && !is_arrow(tokens, i) {
+            depth -= 1;
+        } else if depth == 0 && is_punct(Some(token), ',') {
+            parts.push(Vec::new());
+            continue;
+        }
+
+        parts.last_mut().unwrap().push(token.clone());
+    }
+
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn is_punct(token: Option<&TokenTree>, c: char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == c)
+}
+
+/// Returns `true` if the `>` at `index` is the end of a `->`
+fn is_arrow(tokens: &[TokenTree], index: usize) -> bool {
+    index > 0 && matches!(&tokens[index - 1], TokenTree::Punct(punct) if punct.as_char() == '-' && punct.spacing() == Spacing::Joint)
+}
+
+fn stringify(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
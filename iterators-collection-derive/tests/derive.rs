@@ -0,0 +1,65 @@
+//! The tests of the `ResettableIterator` derive macro, used through the re-export of `iterators-collection`
+
+use iterators_collection::filter::{Exclude, Include};
+use iterators_collection::reset::SliceResetIter;
+use iterators_collection::ResettableIterator;
+
+#[derive(ResettableIterator)]
+struct Squares<I>
+where
+    I: Iterator<Item = i32>,
+{
+    label: &'static str,
+
+    #[resettable_parent]
+    inner: I,
+}
+
+impl<I> Iterator for Squares<I>
+where
+    I: Iterator<Item = i32>,
+{
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        self.inner.next().map(|x| x * x)
+    }
+}
+
+#[derive(ResettableIterator)]
+struct Counted<'a, I: Iterator<Item = i32> = core::ops::Range<i32>>(&'a mut usize, #[resettable_parent] I);
+
+impl<I: Iterator<Item = i32>> Iterator for Counted<'_, I> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        *self.0 += 1;
+        self.1.next()
+    }
+}
+
+#[test]
+fn reset_is_forwarded_to_the_marked_field() {
+    let parent = Exclude::with_blacklist(SliceResetIter::new(&[1, 2, 3, 4]).resettable_map(|x| *x), vec![3]);
+    let mut iter = Squares {
+        label: "squares",
+        inner: parent,
+    };
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 4, 16]);
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 4, 16]);
+    assert_eq!(iter.label, "squares");
+}
+
+#[test]
+fn reset_is_forwarded_in_tuple_structs() {
+    let mut calls = 0;
+    let parent = Include::with_whitelist(SliceResetIter::new(&[0, 1, 2, 3, 4, 5]).resettable_map(|x| *x), vec![1, 2]);
+    let mut iter = Counted(&mut calls, parent);
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 2]);
+    iter.reset();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(calls, 4);
+}
@@ -0,0 +1,635 @@
+//! General purpose adapters changing the way a parent iterator is consumed
+
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+/// Eagerly pulls up to `k` items ahead of the consumer into a buffer and yields them from the front
+/// 
+/// This adapter only changes *when* the parent is polled, not the order of the items: they are yielded exactly in the order the parent produced them
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::adapters::Prefetch;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = Prefetch::new(array.iter().cloned(), 2);
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5]);
+/// ```
+pub struct Prefetch<T>
+where
+    T: Iterator,
+{
+    iterator: T,
+    buffer: VecDeque<T::Item>,
+    k: usize,
+}
+
+impl<T> Prefetch<T>
+where
+    T: Iterator,
+{
+    /// Creates a `Prefetch` keeping up to `k` items buffered ahead of the consumer
+    /// 
+    /// # Panics
+    /// Panics if `k == 0`
+    pub fn new(iterator: T, k: usize) -> Self {
+        assert_ne!(k, 0);
+
+        Self {
+            iterator,
+            buffer: VecDeque::with_capacity(k),
+            k,
+        }
+    }
+
+    /// Returns the number of items currently buffered
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl<T> Iterator for Prefetch<T>
+where
+    T: Iterator,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Top the buffer back up to `k` items
+        while self.buffer.len() < self.k {
+            match self.iterator.next() {
+                Some(i) => self.buffer.push_back(i),
+                None    => break,
+            }
+        }
+
+        self.buffer.pop_front()
+    }
+}
+
+impl<T> crate::ResettableIterator for Prefetch<T>
+where
+    T: crate::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.iterator.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for Prefetch<T>
+where
+    T: Iterator,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.iterator
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.iterator
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.iterator
+    }
+}
+
+/// Applies a function to each sliding window of `k` consecutive items of the parent
+/// 
+/// The first value is yielded once `k` items have been pulled, then one value is yielded for each new item. This is the general tool behind moving sums, moving averages and so on
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::adapters::WindowFold;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = WindowFold::new(array.iter().cloned(), 3, |window: &[i32]| window.iter().sum::<i32>());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![6, 9, 12]);
+/// ```
+pub struct WindowFold<T, F>
+where
+    T: Iterator,
+{
+    iterator: T,
+    window: VecDeque<T::Item>,
+    k: usize,
+    callback: F,
+}
+
+impl<T, F, B> WindowFold<T, F>
+where
+    T: Iterator,
+    F: Fn(&[T::Item]) -> B,
+{
+    /// Creates a `WindowFold` calling `callback` on each window of `k` items
+    /// 
+    /// # Panics
+    /// Panics if `k == 0`
+    pub fn new(iterator: T, k: usize, callback: F) -> Self {
+        assert_ne!(k, 0);
+
+        Self {
+            iterator,
+            window: VecDeque::with_capacity(k),
+            k,
+            callback,
+        }
+    }
+}
+
+impl<T, F, B> Iterator for WindowFold<T, F>
+where
+    T: Iterator,
+    F: Fn(&[T::Item]) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        if self.window.len() == self.k {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < self.k {
+            let item = self.iterator.next()?;
+            self.window.push_back(item);
+        }
+
+        Some((self.callback)(self.window.make_contiguous()))
+    }
+}
+
+impl<T, F, B> crate::ResettableIterator for WindowFold<T, F>
+where
+    T: crate::ResettableIterator,
+    F: Fn(&[T::Item]) -> B,
+{
+    fn reset(&mut self) {
+        self.window.clear();
+        self.iterator.reset();
+    }
+}
+
+impl<T, F> crate::child::ChildIterator for WindowFold<T, F>
+where
+    T: Iterator,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.iterator
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.iterator
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.iterator
+    }
+}
+
+/// Yields owned copies of the windows of `k` consecutive items of the parent, a new window starting every `step` items
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::adapters::SlidingWindows;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = SlidingWindows::new(array.iter().cloned(), 3, 2);
+/// 
+/// assert_eq!(iter.collect::<Vec<Vec<i32>>>(), vec![vec![1, 2, 3], vec![3, 4, 5]]);
+/// ```
+/// 
+/// # Notes
+/// When `step < k` the windows overlap, and when `step > k` the `step - k` items between two windows are skipped
+/// 
+/// When the parent is exhausted, the remaining items form an incomplete window which is dropped by default. Call `set_keep_partial(true)` to yield it as the final window
+pub struct SlidingWindows<T>
+where
+    T: Iterator,
+    T::Item: Clone,
+{
+    iterator: T,
+    window: VecDeque<T::Item>,
+    k: usize,
+    step: usize,
+    keep_partial: bool,
+    started: bool,
+    done: bool,
+}
+
+impl<T> SlidingWindows<T>
+where
+    T: Iterator,
+    T::Item: Clone,
+{
+    /// Creates a `SlidingWindows` of `k` items, starting a new window every `step` items
+    /// 
+    /// # Panics
+    /// Panics if `k == 0` or `step == 0`
+    pub fn new(iterator: T, k: usize, step: usize) -> Self {
+        assert_ne!(k, 0);
+        assert_ne!(step, 0);
+
+        Self {
+            iterator,
+            window: VecDeque::with_capacity(k),
+            k,
+            step,
+            keep_partial: false,
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Sets whether the incomplete window left at the end of the stream is yielded or dropped
+    pub fn set_keep_partial(&mut self, keep_partial: bool) {
+        self.keep_partial = keep_partial;
+    }
+}
+
+impl<T> Iterator for SlidingWindows<T>
+where
+    T: Iterator,
+    T::Item: Clone,
+{
+    type Item = Vec<T::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        // Moves to the start of the next window. The items beyond the current window are skipped if there is a gap
+        if self.started {
+            let mut skipped = self.step.min(self.window.len());
+            self.window.drain(..skipped);
+
+            while skipped < self.step {
+                if self.iterator.next().is_none() {
+                    self.done = true;
+                    return None;
+                }
+
+                skipped += 1;
+            }
+        }
+        self.started = true;
+
+        while self.window.len() < self.k {
+            match self.iterator.next() {
+                Some(i) => self.window.push_back(i),
+                None    => {
+                    self.done = true;
+
+                    return if self.keep_partial && !self.window.is_empty() {
+                        Some(self.window.iter().cloned().collect())
+                    } else {
+                        None
+                    };
+                },
+            }
+        }
+
+        Some(self.window.iter().cloned().collect())
+    }
+}
+
+impl<T> crate::ResettableIterator for SlidingWindows<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Clone,
+{
+    fn reset(&mut self) {
+        self.window.clear();
+        self.started = false;
+        self.done = false;
+        self.iterator.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for SlidingWindows<T>
+where
+    T: Iterator,
+    T::Item: Clone,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.iterator
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.iterator
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.iterator
+    }
+}
+
+/// Reduces each group of `n` consecutive items of the parent to a single value. Not like `WindowFold`, the groups don't overlap
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::adapters::TumblingAggregate;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = TumblingAggregate::new(array.iter().cloned(), 2, |group: &[i32]| group.iter().sum::<i32>());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![3, 7, 5]);
+/// ```
+/// 
+/// # Notes
+/// The incomplete group left at the end of the stream is aggregated too by default. Call `set_keep_partial(false)` to drop it
+pub struct TumblingAggregate<T, F>
+where
+    T: Iterator,
+{
+    iterator: T,
+    buffer: Vec<T::Item>,
+    n: usize,
+    callback: F,
+    keep_partial: bool,
+}
+
+impl<T, F, B> TumblingAggregate<T, F>
+where
+    T: Iterator,
+    F: Fn(&[T::Item]) -> B,
+{
+    /// Creates a `TumblingAggregate` calling `callback` on each group of `n` items
+    /// 
+    /// # Panics
+    /// Panics if `n == 0`
+    pub fn new(iterator: T, n: usize, callback: F) -> Self {
+        assert_ne!(n, 0);
+
+        Self {
+            iterator,
+            buffer: Vec::with_capacity(n),
+            n,
+            callback,
+            keep_partial: true,
+        }
+    }
+
+    /// Sets whether the incomplete group left at the end of the stream is aggregated or dropped
+    pub fn set_keep_partial(&mut self, keep_partial: bool) {
+        self.keep_partial = keep_partial;
+    }
+}
+
+impl<T, F, B> Iterator for TumblingAggregate<T, F>
+where
+    T: Iterator,
+    F: Fn(&[T::Item]) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        self.buffer.clear();
+
+        while self.buffer.len() < self.n {
+            match self.iterator.next() {
+                Some(i) => self.buffer.push(i),
+                None    => break,
+            }
+        }
+
+        if self.buffer.len() == self.n || (self.keep_partial && !self.buffer.is_empty()) {
+            Some((self.callback)(&self.buffer))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, F, B> crate::ResettableIterator for TumblingAggregate<T, F>
+where
+    T: crate::ResettableIterator,
+    F: Fn(&[T::Item]) -> B,
+{
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.iterator.reset();
+    }
+}
+
+impl<T, F> crate::child::ChildIterator for TumblingAggregate<T, F>
+where
+    T: Iterator,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.iterator
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.iterator
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.iterator
+    }
+}
+
+/// Yields the median of all the items seen so far at each step, using a max-heap for the lower half and a min-heap for the upper half
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::adapters::RunningMedian;
+/// 
+/// let array = [5, 1, 3, 8, 9];
+/// let iter = RunningMedian::new(array.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![5, 1, 3, 3, 5]);
+/// ```
+/// 
+/// # Notes
+/// Since `Ord` items can't be averaged, the lower of the two middle items is yielded when an even number of items has been seen. Both of them can be read with `middles`
+/// 
+/// Each item is inserted in O(log n) but all of them are kept
+pub struct RunningMedian<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    iterator: T,
+    lower: BinaryHeap<T::Item>,
+    upper: BinaryHeap<Reverse<T::Item>>,
+}
+
+impl<T> RunningMedian<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    /// Creates a `RunningMedian` with no item seen yet
+    pub fn new(iterator: T) -> Self {
+        Self {
+            iterator,
+            lower: BinaryHeap::new(),
+            upper: BinaryHeap::new(),
+        }
+    }
+
+    /// Returns the two middle items of the items seen so far, which are the same when an odd number of items has been seen, or `None` if no item has been seen
+    pub fn middles(&self) -> Option<(&T::Item, &T::Item)> {
+        let lower = self.lower.peek()?;
+
+        if self.lower.len() == self.upper.len() {
+            Some((lower, &self.upper.peek()?.0))
+        } else {
+            Some((lower, lower))
+        }
+    }
+}
+
+impl<T> Iterator for RunningMedian<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+
+        match self.lower.peek() {
+            Some(max) if &item > max => self.upper.push(Reverse(item)),
+            _                        => self.lower.push(item),
+        }
+
+        // The lower half keeps as many items as the upper one, or one more
+        if self.lower.len() > self.upper.len() + 1 {
+            let moved = self.lower.pop().unwrap();
+            self.upper.push(Reverse(moved));
+        } else if self.upper.len() > self.lower.len() {
+            let moved = self.upper.pop().unwrap().0;
+            self.lower.push(moved);
+        }
+
+        self.lower.peek().cloned()
+    }
+}
+
+impl<T> crate::ResettableIterator for RunningMedian<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Ord + Clone,
+{
+    fn reset(&mut self) {
+        self.lower.clear();
+        self.upper.clear();
+        self.iterator.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for RunningMedian<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.iterator
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.iterator
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.iterator
+    }
+}
+
+/// Tags each item with a global sequence number which keeps increasing even after a reset
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::adapters::GlobalSeq;
+/// 
+/// let array = ['a', 'b', 'c'];
+/// let iter = GlobalSeq::new(array.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<(u64, char)>>(), vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+/// ```
+/// 
+/// # Notes
+/// Resetting a `GlobalSeq` only resets its parent, the counter must be zeroed explicitly with `reset_counter`
+pub struct GlobalSeq<T> {
+    iterator: T,
+    counter: u64,
+}
+
+impl<T> GlobalSeq<T>
+where
+    T: Iterator,
+{
+    /// Creates a `GlobalSeq` with a counter starting at 0
+    pub fn new(iterator: T) -> Self {
+        Self {
+            iterator,
+            counter: 0,
+        }
+    }
+
+    /// Sets the counter back to 0
+    pub fn reset_counter(&mut self) {
+        self.counter = 0;
+    }
+}
+
+impl<T> Iterator for GlobalSeq<T>
+where
+    T: Iterator,
+{
+    type Item = (u64, T::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iterator.next()?;
+        self.counter += 1;
+
+        Some((self.counter - 1, item))
+    }
+}
+
+impl<T> crate::ResettableIterator for GlobalSeq<T>
+where
+    T: crate::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for GlobalSeq<T>
+where
+    T: Iterator,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.iterator
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.iterator
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.iterator
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
@@ -0,0 +1,178 @@
+//! Unit tests for the adapters module
+
+use crate::adapters::*;
+use crate::ResettableIterator;
+
+/// A resettable counter used as a parent in the tests below
+struct Count {
+    pub cur: u32,
+    pub end: u32,
+    pub polled: u32,
+}
+
+impl Count {
+    fn new(end: u32) -> Self {
+        Self {
+            cur: 0,
+            end,
+            polled: 0,
+        }
+    }
+}
+
+impl Iterator for Count {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.polled += 1;
+
+        if self.cur == self.end {
+            None
+        } else {
+            self.cur += 1;
+            Some(self.cur - 1)
+        }
+    }
+}
+
+impl ResettableIterator for Count {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+#[test]
+fn prefetch_keeps_ordering() {
+    let iter = Prefetch::new(Count::new(6), 3);
+
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn prefetch_pulls_ahead() {
+    use crate::child::ChildIterator;
+
+    let mut iter = Prefetch::new(Count::new(6), 3);
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.buffered(), 2);
+    assert_eq!(iter.get_parent().polled, 3);
+}
+
+#[test]
+fn prefetch_resets_well() {
+    let mut iter = Prefetch::new(Count::new(6), 3);
+    iter.next();
+    iter.next();
+
+    iter.reset();
+    assert_eq!(iter.buffered(), 0);
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[should_panic]
+fn prefetch_panics_with_zero_lookahead() {
+    let _iter = Prefetch::new(Count::new(6), 0);
+}
+
+#[test]
+fn window_fold_moving_average() {
+    let iter = WindowFold::new(Count::new(6), 2, |window: &[u32]| {
+        window.iter().sum::<u32>() as f64 / window.len() as f64
+    });
+
+    assert_eq!(iter.collect::<Vec<f64>>(), vec![0.5, 1.5, 2.5, 3.5, 4.5]);
+}
+
+#[test]
+fn window_fold_with_too_short_parent() {
+    let mut iter = WindowFold::new(Count::new(2), 3, |window: &[u32]| window.len());
+
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn window_fold_resets_well() {
+    let mut iter = WindowFold::new(Count::new(6), 3, |window: &[u32]| window.to_vec());
+    iter.next();
+    iter.next();
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(vec![0, 1, 2]));
+}
+
+#[test]
+fn sliding_windows_overlapping() {
+    let iter = SlidingWindows::new(Count::new(5), 3, 1);
+
+    assert_eq!(iter.collect::<Vec<Vec<u32>>>(), vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+}
+
+#[test]
+fn sliding_windows_with_gaps() {
+    let iter = SlidingWindows::new(Count::new(8), 2, 3);
+
+    assert_eq!(iter.collect::<Vec<Vec<u32>>>(), vec![vec![0, 1], vec![3, 4], vec![6, 7]]);
+}
+
+#[test]
+fn sliding_windows_partial_final_window() {
+    let mut iter = SlidingWindows::new(Count::new(7), 3, 3);
+    assert_eq!(iter.by_ref().collect::<Vec<Vec<u32>>>(), vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+    iter.reset();
+    iter.set_keep_partial(true);
+    assert_eq!(iter.collect::<Vec<Vec<u32>>>(), vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+}
+
+#[test]
+fn tumbling_aggregate_keeps_partial_group() {
+    let iter = TumblingAggregate::new(Count::new(7), 3, |group: &[u32]| group.to_vec());
+
+    assert_eq!(iter.collect::<Vec<Vec<u32>>>(), vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+}
+
+#[test]
+fn tumbling_aggregate_drops_partial_group() {
+    let mut iter = TumblingAggregate::new(Count::new(7), 3, |group: &[u32]| group.iter().sum::<u32>());
+    iter.set_keep_partial(false);
+    assert_eq!(iter.by_ref().collect::<Vec<u32>>(), vec![3, 12]);
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn running_median() {
+    let array = [7, 3, 3, 10, 1, 6, 8];
+    let mut iter = RunningMedian::new(array.iter().cloned());
+    assert_eq!(iter.middles(), None);
+
+    assert_eq!(iter.by_ref().take(4).collect::<Vec<i32>>(), vec![7, 3, 3, 3]);
+    assert_eq!(iter.middles(), Some((&3, &7)));
+
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![3, 3, 6]);
+}
+
+#[test]
+fn running_median_resets_well() {
+    let mut iter = RunningMedian::new(Count::new(5));
+    iter.by_ref().for_each(drop);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<u32>>(), vec![0, 0, 1, 1, 2]);
+}
+
+#[test]
+fn global_seq_survives_reset() {
+    let mut iter = GlobalSeq::new(Count::new(3));
+    assert_eq!(iter.by_ref().collect::<Vec<(u64, u32)>>(), vec![(0, 0), (1, 1), (2, 2)]);
+
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<(u64, u32)>>(), vec![(3, 0), (4, 1), (5, 2)]);
+
+    iter.reset();
+    iter.reset_counter();
+    assert_eq!(iter.next(), Some((0, 0)));
+}
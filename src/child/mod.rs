@@ -12,4 +12,137 @@ pub trait ChildIterator {
 
     /// Returns a reference to the parent of `self`
     fn get_parent(&self) -> &Self::Parent;
+
+    /// Returns a reference to the bottom-most iterator of the chain of parents of `self`
+    /// 
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "alloc")]
+    /// # fn main() {
+    /// use iterators_collection::child::ChildIterator;
+    /// use iterators_collection::filter::Exclude;
+    /// 
+    /// let iter = Exclude::with_blacklist(1..10, vec![2]);
+    /// let iter = Exclude::with_blacklist(iter, vec![3]);
+    /// 
+    /// assert_eq!(iter.root(), &(1..10));
+    /// # }
+    /// # #[cfg(not(feature = "alloc"))]
+    /// # fn main() {}
+    /// ```
+    fn root(&self) -> &<Self::Parent as Rooted>::Root
+    where
+        Self::Parent: Rooted,
+    {
+        self.get_parent().as_root()
+    }
+
+    /// Returns a mutable reference to the bottom-most iterator of the chain of parents of `self`
+    fn root_mut(&mut self) -> &mut <Self::Parent as Rooted>::Root
+    where
+        Self::Parent: Rooted,
+    {
+        self.get_parent_mut().as_root_mut()
+    }
+
+    /// Resets the whole chain of iterators, from `self` to the bottom-most one, clearing the state of each of them exactly once
+    /// 
+    /// # Notes
+    /// It relies on the contract of the `ResettableIterator` implementations of `ChildIterator`s: resetting a child clears its own state and resets its parent, which resets its own parent in turn. All the `ChildIterator`s of this crate follow it, and yours must do the same, so calling `reset` on the top of the chain reaches every iterator once without walking the parents again
+    fn reset_chain(&mut self)
+    where
+        Self: crate::ResettableIterator + Sized,
+        Self::Parent: crate::ResettableIterator,
+    {
+        crate::ResettableIterator::reset(self);
+    }
+}
+
+/// A trait that means the bottom-most iterator of a chain of `ChildIterator`s can be reached from `Self`
+/// 
+/// It is implemented by all the `ChildIterator`s whose parent implements it, and by the iterators of the standard library, which are the bottom of the chains. If the bottom of a chain is one of your own iterators, implement it with `type Root = Self`
+pub trait Rooted {
+    /// The bottom-most iterator of the chain
+    type Root;
+
+    /// Returns a reference to the bottom-most iterator of the chain
+    fn as_root(&self) -> &Self::Root;
+
+    /// Returns a mutable reference to the bottom-most iterator of the chain
+    fn as_root_mut(&mut self) -> &mut Self::Root;
+}
+
+impl<C> Rooted for C
+where
+    C: ChildIterator,
+    C::Parent: Rooted,
+{
+    type Root = <C::Parent as Rooted>::Root;
+
+    fn as_root(&self) -> &Self::Root {
+        self.get_parent().as_root()
+    }
+
+    fn as_root_mut(&mut self) -> &mut Self::Root {
+        self.get_parent_mut().as_root_mut()
+    }
 }
+
+/// Implements `Rooted` for some iterators being the bottom of their chains
+macro_rules! impl_rooted {
+    ($($ty:ty => [$($generics:tt)*]),* $(,)?) => {
+        $(
+            impl<$($generics)*> Rooted for $ty {
+                type Root = Self;
+
+                fn as_root(&self) -> &Self {
+                    self
+                }
+
+                fn as_root_mut(&mut self) -> &mut Self {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_rooted! {
+    core::ops::Range<A> => [A],
+    core::ops::RangeFrom<A> => [A],
+    core::ops::RangeInclusive<A> => [A],
+    core::slice::Iter<'a, T> => ['a, T],
+    core::slice::IterMut<'a, T> => ['a, T],
+    core::str::Chars<'a> => ['a],
+    core::iter::Empty<T> => [T],
+    core::iter::Once<T> => [T],
+    core::iter::Repeat<T> => [T],
+    core::iter::Cloned<I> => [I],
+    core::iter::Copied<I> => [I],
+    core::iter::Map<I, F> => [I, F],
+    core::iter::Filter<I, P> => [I, P],
+    core::iter::Enumerate<I> => [I],
+    core::iter::Rev<I> => [I],
+    core::iter::Take<I> => [I],
+    core::iter::Skip<I> => [I],
+    core::iter::StepBy<I> => [I],
+    core::iter::Chain<A, B> => [A, B],
+    core::iter::Zip<A, B> => [A, B],
+    crate::reset::SliceResetIter<'a, T> => ['a, T],
+    crate::reset::RangeResetIter => [],
+}
+
+#[cfg(feature = "alloc")]
+impl_rooted! {
+    alloc::vec::IntoIter<T> => [T],
+    alloc::collections::vec_deque::IntoIter<T> => [T],
+    crate::reset::VecResetIter<T> => [T],
+}
+
+#[cfg(feature = "std")]
+impl_rooted! {
+    std::collections::hash_set::IntoIter<T> => [T],
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
@@ -0,0 +1,100 @@
+//! The unit-tests module for the child module
+
+use crate::child::ChildIterator;
+use crate::filter::{Exclude, Unique};
+use crate::ResettableIterator;
+
+struct Values {
+    values: Vec<i32>,
+    cur: usize,
+}
+
+impl Iterator for Values {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        let item = self.values.get(self.cur).cloned();
+        self.cur += 1;
+        item
+    }
+}
+
+impl ResettableIterator for Values {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+#[test]
+fn root_mut_reaches_the_base_iterator() {
+    let iter = Exclude::with_blacklist(vec![1, 2, 3, 4, 5].into_iter(), vec![2]);
+    let mut iter = Exclude::with_blacklist(iter, vec![4]);
+
+    // Consumes the base iterator behind the back of the filters
+    assert_eq!(iter.root_mut().next(), Some(1));
+    assert_eq!(iter.root().len(), 4);
+
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![3, 5]);
+}
+
+#[test]
+fn reset_chain_resets_every_filter() {
+    let values = Values { values: vec![1, 2, 2, 3, 1, 4], cur: 0 };
+    let mut iter = Exclude::with_blacklist(Unique::new(values), vec![3]);
+
+    let first = iter.by_ref().collect::<Vec<i32>>();
+    assert_eq!(first, vec![1, 2, 4]);
+
+    // The values already seen by `Unique` are forgotten too
+    iter.reset_chain();
+    assert_eq!(iter.collect::<Vec<i32>>(), first);
+}
+
+#[test]
+fn reset_chain_resets_each_level_once() {
+    use crate::diagnostics::CountResets;
+    use crate::filter::Limit;
+    use crate::reset::SliceResetIter;
+
+    let array = [1, 2, 3, 4];
+    let mut iter = Limit::new(Limit::new(Limit::new(CountResets::new(SliceResetIter::new(&array)), 3), 3), 3);
+    assert_eq!(iter.by_ref().count(), 3);
+
+    iter.reset_chain();
+    assert_eq!(iter.root().len(), 4);
+    assert_eq!(iter.get_parent().get_parent().get_parent().reset_count(), 1);
+}
+
+#[test]
+fn reset_chain_accepts_any_resettable_bottom() {
+    use crate::filter::Limit;
+    use crate::share::DoubleIterator;
+
+    let mut array = [1, 2, 3];
+    let mut iter = Limit::new(DoubleIterator::new(&mut array), 2);
+    assert_eq!(iter.by_ref().count(), 2);
+
+    iter.reset_chain();
+    assert_eq!(iter.count(), 2);
+}
+
+#[test]
+fn reset_iterators_are_roots() {
+    use crate::child::Rooted;
+    use crate::filter::Limit;
+    use crate::reset::{RangeResetIter, SliceResetIter, VecResetIter};
+
+    let array = [1, 2, 3];
+    let mut iter = Limit::new(Limit::new(SliceResetIter::new(&array), 2), 2);
+    iter.next();
+    assert_eq!(iter.as_root().as_slice(), &[1, 2, 3]);
+    assert_eq!(iter.as_root_mut().next(), Some(&2));
+
+    let mut iter = Limit::new(RangeResetIter::new(0..3), 2);
+    iter.as_root_mut().next();
+    assert_eq!(iter.next(), Some(1));
+
+    let mut iter = Limit::new(VecResetIter::new(vec![1, 2, 3]), 2);
+    iter.as_root_mut().next();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 3]);
+}
@@ -0,0 +1,449 @@
+//! The iterators in this module merge the objects of several parent iterators into one stream
+
+use core::iter::FusedIterator;
+
+/// Yields the objects of two iterators alternately, starting with the first one. Once one of them is exhausted, the remaining objects of the other one are yielded in order
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::combine::Interleave;
+/// 
+/// let iter = Interleave::new(vec![1, 3, 5].into_iter(), vec![2, 4].into_iter());
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interleave<A, B> {
+    cur: A,
+    second: B,
+    on_second: bool,
+}
+
+impl<A, B> Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    /// Returns a new object alternating between the objects of `first` and the ones of `second`
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            cur: first,
+            second,
+            on_second: false,
+        }
+    }
+
+    /// Returns a reference to the second parent. The first one is given by `ChildIterator::get_parent`
+    pub fn get_second(&self) -> &B {
+        &self.second
+    }
+
+    /// Returns a mutable reference to the second parent. The first one is given by `ChildIterator::get_parent_mut`
+    pub fn get_second_mut(&mut self) -> &mut B {
+        &mut self.second
+    }
+
+    /// Destroys `self` and returns both parents
+    pub fn release_parents(self) -> (A, B) {
+        (self.cur, self.second)
+    }
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let returned = if self.on_second {
+            self.second.next().or_else(|| self.cur.next())
+        } else {
+            self.cur.next().or_else(|| self.second.next())
+        };
+        self.on_second = !self.on_second;
+
+        returned
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_min, first_max) = self.cur.size_hint();
+        let (second_min, second_max) = self.second.size_hint();
+
+        let max = match (first_max, second_max) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _                  => None,
+        };
+
+        (first_min.saturating_add(second_min), max)
+    }
+}
+
+impl<A, B> FusedIterator for Interleave<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+{
+}
+
+impl<A, B> crate::ResettableIterator for Interleave<A, B>
+where
+    A: crate::ResettableIterator,
+    B: crate::ResettableIterator<Item = A::Item>,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.second.reset();
+        self.on_second = false;
+    }
+}
+
+impl<A, B> crate::child::ChildIterator for Interleave<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Parent = A;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Yields all the objects of a first iterator, then all the ones of a second iterator. Unlike `core::iter::Chain`, it can be reset and gives access to both parents
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::combine::Chain;
+/// 
+/// let iter = Chain::new(vec![1, 2].into_iter(), vec![3].into_iter());
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Chain<A, B> {
+    cur: A,
+    second: B,
+    on_second: bool,
+}
+
+impl<A, B> Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    /// Returns a new object yielding the objects of `first` followed by the ones of `second`
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            cur: first,
+            second,
+            on_second: false,
+        }
+    }
+
+    /// Returns a reference to the second parent. The first one is given by `ChildIterator::get_parent`
+    pub fn get_second(&self) -> &B {
+        &self.second
+    }
+
+    /// Returns a mutable reference to the second parent. The first one is given by `ChildIterator::get_parent_mut`
+    pub fn get_second_mut(&mut self) -> &mut B {
+        &mut self.second
+    }
+
+    /// Returns `true` if the first parent is exhausted and the objects now come from the second one
+    pub fn is_on_second(&self) -> bool {
+        self.on_second
+    }
+
+    /// Destroys `self` and returns both parents
+    pub fn release_parents(self) -> (A, B) {
+        (self.cur, self.second)
+    }
+}
+
+impl<A, B> Iterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.on_second {
+            match self.cur.next() {
+                Some(i) => return Some(i),
+                None    => self.on_second = true,
+            }
+        }
+
+        self.second.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (second_min, second_max) = self.second.size_hint();
+        if self.on_second {
+            return (second_min, second_max);
+        }
+
+        let (first_min, first_max) = self.cur.size_hint();
+        let max = match (first_max, second_max) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _                  => None,
+        };
+
+        (first_min.saturating_add(second_min), max)
+    }
+}
+
+impl<A, B> FusedIterator for Chain<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+{
+}
+
+impl<A, B> crate::ResettableIterator for Chain<A, B>
+where
+    A: crate::ResettableIterator,
+    B: crate::ResettableIterator<Item = A::Item>,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.second.reset();
+        self.on_second = false;
+    }
+}
+
+impl<A, B> crate::child::ChildIterator for Chain<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Parent = A;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Merges two sorted iterators into one sorted stream, by comparing the next objects of both of them. When two objects are equal, the one of the first iterator is yielded first
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::combine::Merge;
+/// 
+/// let iter = Merge::new(vec![1, 4, 5].into_iter(), vec![2, 3, 6].into_iter());
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5, 6]);
+/// ```
+/// 
+/// # Notes
+/// If the parents are not sorted, the objects are still all yielded but the result is not sorted either
+/// 
+/// The next object of each parent is taken in advance to be compared, so the parents may be one object ahead of what has been yielded
+#[derive(Clone, Debug)]
+pub struct Merge<A, B>
+where
+    A: Iterator,
+{
+    cur: A,
+    second: B,
+    first_peeked: Option<A::Item>,
+    second_peeked: Option<A::Item>,
+}
+
+impl<A, B> Merge<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    /// Returns a new object merging the sorted iterators `first` and `second`
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            cur: first,
+            second,
+            first_peeked: None,
+            second_peeked: None,
+        }
+    }
+
+    /// Returns a reference to the second parent. The first one is given by `ChildIterator::get_parent`
+    pub fn get_second(&self) -> &B {
+        &self.second
+    }
+
+    /// Returns a mutable reference to the second parent. The first one is given by `ChildIterator::get_parent_mut`
+    pub fn get_second_mut(&mut self) -> &mut B {
+        &mut self.second
+    }
+
+    /// Destroys `self` and returns both parents. The objects taken in advance are lost
+    pub fn release_parents(self) -> (A, B) {
+        (self.cur, self.second)
+    }
+}
+
+impl<A, B> Iterator for Merge<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first_peeked.is_none() {
+            self.first_peeked = self.cur.next();
+        }
+        if self.second_peeked.is_none() {
+            self.second_peeked = self.second.next();
+        }
+
+        match (&self.first_peeked, &self.second_peeked) {
+            (Some(a), Some(b)) if b < a => self.second_peeked.take(),
+            (Some(_), _)                => self.first_peeked.take(),
+            (None, _)                   => self.second_peeked.take(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = self.first_peeked.is_some() as usize + self.second_peeked.is_some() as usize;
+        let (first_min, first_max) = self.cur.size_hint();
+        let (second_min, second_max) = self.second.size_hint();
+
+        let max = match (first_max, second_max) {
+            (Some(a), Some(b)) => a.checked_add(b).and_then(|max| max.checked_add(peeked)),
+            _                  => None,
+        };
+
+        (first_min.saturating_add(second_min).saturating_add(peeked), max)
+    }
+}
+
+impl<A, B> FusedIterator for Merge<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+    A::Item: Ord,
+{
+}
+
+impl<A, B> crate::ResettableIterator for Merge<A, B>
+where
+    A: crate::ResettableIterator,
+    B: crate::ResettableIterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.second.reset();
+        self.first_peeked = None;
+        self.second_peeked = None;
+    }
+}
+
+impl<A, B> crate::child::ChildIterator for Merge<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    type Parent = A;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Repeats a resettable iterator endlessly, resetting it each time it is exhausted
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::combine::CycleReset;
+/// use iterators_collection::reset::SliceResetIter;
+/// 
+/// let array = [1, 2, 3];
+/// let iter = CycleReset::new(SliceResetIter::new(&array));
+/// 
+/// assert_eq!(iter.take(7).cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 1, 2, 3, 1]);
+/// ```
+/// 
+/// # Notes
+/// `None` is returned only if the parent yields nothing right after being reset, so an empty parent doesn't lead to an infinite loop
+#[derive(Clone, Debug)]
+pub struct CycleReset<T> {
+    cur: T,
+}
+
+impl<T: crate::ResettableIterator> CycleReset<T> {
+    /// Returns a new object repeating the objects of `iterator`
+    pub fn new(iterator: T) -> Self {
+        Self {
+            cur: iterator,
+        }
+    }
+}
+
+impl<T: crate::ResettableIterator> Iterator for CycleReset<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.cur.next() {
+            None => {
+                self.cur.reset();
+                self.cur.next()
+            },
+
+            item => item,
+        }
+    }
+}
+
+impl<T: crate::ResettableIterator> crate::ResettableIterator for CycleReset<T> {
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T: Iterator> crate::child::ChildIterator for CycleReset<T> {
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
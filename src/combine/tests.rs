@@ -0,0 +1,122 @@
+//! Unit tests for the combine module
+
+use crate::child::ChildIterator;
+use crate::combine::{Chain, CycleReset, Interleave, Merge};
+use crate::filter::Exclude;
+use crate::reset::SliceResetIter;
+use crate::ResettableIterator;
+
+#[test]
+fn interleave() {
+    let odd = [1, 3, 5];
+    let even = [2, 4];
+    let mut iter = Interleave::new(SliceResetIter::new(&odd), SliceResetIter::new(&even));
+
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5]);
+
+    assert_eq!(iter.get_parent().as_slice(), &odd);
+    assert_eq!(iter.get_second().as_slice(), &even);
+    iter.get_second_mut().reset();
+    assert_eq!(iter.next(), Some(&2));
+
+    let (first, second) = iter.release_parents();
+    assert_eq!(first.len(), 0);
+    assert_eq!(second.len(), 1);
+}
+
+#[test]
+fn interleave_longer_second() {
+    let iter = Interleave::new(vec![1].into_iter(), vec![2, 3, 4].into_iter());
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+
+    let iter = Interleave::new(Vec::new().into_iter(), vec![2, 3].into_iter());
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 3]);
+}
+
+
+#[test]
+fn chain() {
+    let low = [1, 2, 3, 4];
+    let high = [5, 6, 7, 8];
+    let mut iter = Chain::new(
+        Exclude::with_blacklist(SliceResetIter::new(&low).resettable_map(|x| *x), vec![2]),
+        Exclude::with_blacklist(SliceResetIter::new(&high).resettable_map(|x| *x), vec![5, 8]),
+    );
+
+    let first_pass = iter.by_ref().collect::<Vec<i32>>();
+    assert_eq!(first_pass, vec![1, 3, 4, 6, 7]);
+    assert!(iter.is_on_second());
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert!(!iter.is_on_second());
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), first_pass);
+
+    assert!(iter.get_parent().is_excluded(&2));
+    iter.get_second_mut().exclude(6);
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4, 7]);
+
+    let (first, second) = iter.release_parents();
+    assert!(first.is_excluded(&2));
+    assert!(second.is_excluded(&6));
+
+    let mut iter = Chain::new(SliceResetIter::new(&low), SliceResetIter::new(&high));
+    assert_eq!(iter.size_hint(), (8, Some(8)));
+    iter.by_ref().nth(4);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+}
+#[test]
+fn cycle_reset() {
+    let array = [1, 2, 3, 4];
+    let source = Exclude::with_blacklist(SliceResetIter::new(&array).resettable_map(|x| *x), vec![2]);
+    let mut iter = CycleReset::new(source);
+
+    assert_eq!(iter.by_ref().take(7).collect::<Vec<i32>>(), vec![1, 3, 4, 1, 3, 4, 1]);
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(1));
+
+    iter.get_parent_mut().exclude(1);
+    iter.get_parent_mut().exclude(3);
+    iter.get_parent_mut().exclude(4);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn merge() {
+    let first = [1, 4, 5];
+    let second = [2, 3, 6];
+    let mut iter = Merge::new(SliceResetIter::new(&first), SliceResetIter::new(&second));
+
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![2, 3, 4, 5, 6]);
+
+    assert_eq!(iter.get_parent().as_slice(), &first);
+    assert_eq!(iter.get_second().as_slice(), &second);
+}
+
+#[test]
+fn merge_uneven_lengths() {
+    let first = vec![(1, 'a'), (3, 'a')];
+    let second = vec![(1, 'b'), (2, 'b'), (3, 'b'), (7, 'b')];
+
+    let iter = Merge::new(first.into_iter(), second.into_iter());
+    let merged = iter.collect::<Vec<(i32, char)>>();
+    assert_eq!(merged, vec![(1, 'a'), (1, 'b'), (2, 'b'), (3, 'a'), (3, 'b'), (7, 'b')]);
+
+    let iter = Merge::new(Vec::new().into_iter(), vec![1, 2].into_iter());
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2]);
+}
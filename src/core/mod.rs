@@ -3,6 +3,8 @@
 /// A resettable iterator. It means that calling a `reset` method will set the iterator to the first position
 pub trait ResettableIterator: Iterator {
     /// Resets the iterator to its initial state when called
+    /// 
+    /// If `self` is a `ChildIterator`, it must reset its parent as well as its own state, so that resetting the top of a chain resets all of it. See `ChildIterator::reset_chain`
     fn reset(&mut self);
 
     /// Creates a new iterator from the current one and reset it
@@ -15,6 +17,19 @@ pub trait ResettableIterator: Iterator {
         new
     }
 
+    /// Calls `body` on the iterator `passes` times, resetting it after each call. `body` can consume the iterator the way it wants
+    /// 
+    /// The iterator is reset after the last pass too, so it is left at its initial state
+    fn for_each_pass<F>(&mut self, passes: usize, mut body: F)
+    where
+        F: FnMut(&mut Self),
+    {
+        for _ in 0..passes {
+            body(self);
+            self.reset();
+        }
+    }
+
     /// Creates a ResettableMap from the current iterator
     fn resettable_map<F, R>(self, callback: F) -> self::ResettableMap<Self, F>
     where
@@ -28,6 +43,18 @@ pub trait ResettableIterator: Iterator {
     }
 }
 
+/// A resettable iterator which can also be reset to an arbitrary position rather than to the first one
+/// 
+/// # Notes
+/// The type of the position depends on the iterator, which is why this is not a method of `ResettableIterator` itself
+pub trait ResettableToIterator: ResettableIterator {
+    /// The type describing a position of the iterator
+    type Position;
+
+    /// Resets the iterator and moves it to `position`, so the next item yielded is the one at `position`
+    fn reset_to(&mut self, position: Self::Position);
+}
+
 /// A resettable version of `std::iter::Map`. A simple trait implementation is not suitable because it requires to get access to private elements of `std::iter::Map` like the iterator stored
 /// 
 /// You can use it like you would use `std::iter::Map` but it implements the `ResettableIterator` trait too
@@ -82,5 +109,5 @@ where
 }
 
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
@@ -45,6 +45,17 @@ fn count_resets_well() {
     assert_eq!(iter.next(), Some(0));
 }
 
+#[test]
+fn for_each_pass() {
+    let mut iter = Count { begin: 0, cur: 0, end: 4 };
+    let mut sums = Vec::new();
+
+    iter.for_each_pass(3, |iter| sums.push(iter.sum::<u32>()));
+
+    assert_eq!(sums, vec![6, 6, 6]);
+    assert_eq!(iter.next(), Some(0));
+}
+
 #[test]
 fn resettable_map_iterates_well() {
     let mut counter = 0;
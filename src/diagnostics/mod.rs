@@ -0,0 +1,80 @@
+//! Some wrappers giving information about the way a chain of iterators is used, useful to tune it
+
+use core::iter::FusedIterator;
+
+/// Counts how many times an iterator has been reset, forwarding everything else to it
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::diagnostics::CountResets;
+/// use iterators_collection::reset::SliceResetIter;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let array = [1, 2, 3];
+/// let mut iter = CountResets::new(SliceResetIter::new(&array));
+/// 
+/// iter.for_each_pass(2, |iter| iter.for_each(drop));
+/// assert_eq!(iter.reset_count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CountResets<T> {
+    cur: T,
+    resets: usize,
+}
+
+impl<T: Iterator> CountResets<T> {
+    /// Returns a new object wrapping `iterator`, which hasn't been reset yet
+    pub fn new(iterator: T) -> Self {
+        Self {
+            cur: iterator,
+            resets: 0,
+        }
+    }
+
+    /// Returns the number of times `reset` has been called since the creation of `self`
+    pub fn reset_count(&self) -> usize {
+        self.resets
+    }
+}
+
+impl<T: Iterator> Iterator for CountResets<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cur.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cur.size_hint()
+    }
+}
+
+impl<T: ExactSizeIterator> ExactSizeIterator for CountResets<T> {}
+
+impl<T: FusedIterator> FusedIterator for CountResets<T> {}
+
+impl<T: crate::ResettableIterator> crate::ResettableIterator for CountResets<T> {
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.resets += 1;
+    }
+}
+
+impl<T: Iterator> crate::child::ChildIterator for CountResets<T> {
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
@@ -0,0 +1,25 @@
+//! Unit tests for the diagnostics module
+
+use crate::child::ChildIterator;
+use crate::diagnostics::CountResets;
+use crate::filter::Exclude;
+use crate::reset::SliceResetIter;
+use crate::ResettableIterator;
+
+#[test]
+fn count_resets() {
+    let array = [1, 2, 3, 4];
+    let iter = Exclude::with_blacklist(SliceResetIter::new(&array).resettable_map(|x| *x), vec![2]);
+    let mut iter = CountResets::new(iter);
+    assert_eq!(iter.reset_count(), 0);
+
+    for _ in 0..3 {
+        assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+        iter.reset();
+    }
+    assert_eq!(iter.reset_count(), 3);
+
+    assert!(iter.get_parent().is_excluded(&2));
+    iter.get_parent_mut().exclude(3);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 4]);
+}
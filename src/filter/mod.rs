@@ -1,5 +1,14 @@
 //! The iterators in this module aim to select the elements to yield
 
+use alloc::borrow::ToOwned;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::iter::FusedIterator;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
 /// Excludes an object from iteration. Based on a blacklist
 /// 
 /// # Example
@@ -22,6 +31,9 @@ where
 {
     excluded: Vec<T::Item>,
     cur: T,
+    #[cfg(feature = "std")]
+    distinct: Option<DistinctTracker<T::Item>>,
+    dropped: usize,
 }
 
 impl<T> Exclude<T>
@@ -34,14 +46,80 @@ where
         Exclude {
             cur: iterator,
             excluded: Vec::new(),
+            #[cfg(feature = "std")]
+            distinct: None,
+            dropped: 0,
         }
     }
 
     /// Returns a new object with the given blacklist
     pub fn with_blacklist(iterator: T, blacklist: Vec<T::Item>) -> Self {
+        Self::with_blacklist_iter(iterator, blacklist)
+    }
+
+    /// Returns a new object with a blacklist made of the objects yielded by `blacklist`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::filter::Exclude;
+    /// 
+    /// let iter = Exclude::with_blacklist_iter(0..10, (0..10).filter(|x| x % 3 == 0));
+    /// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 4, 5, 7, 8]);
+    /// ```
+    pub fn with_blacklist_iter<I: IntoIterator<Item = T::Item>>(iterator: T, blacklist: I) -> Self {
         Exclude {
             cur: iterator,
-            excluded: blacklist,
+            excluded: blacklist.into_iter().collect(),
+            #[cfg(feature = "std")]
+            distinct: None,
+            dropped: 0,
+        }
+    }
+
+    /// Returns a new object with an empty blacklist able to hold at least `capacity` objects without reallocating
+    pub fn with_capacity(iterator: T, capacity: usize) -> Self {
+        Self::with_blacklist(iterator, Vec::with_capacity(capacity))
+    }
+
+    /// Returns the number of objects inside the blacklist
+    pub fn blacklist_len(&self) -> usize {
+        self.excluded.len()
+    }
+
+    /// Returns an iterator over the objects of the blacklist, in the order they have been added
+    pub fn iter_blacklist(&self) -> core::slice::Iter<'_, T::Item> {
+        self.excluded.iter()
+    }
+
+    /// Returns the blacklist as a slice, in the order the objects have been added
+    pub fn excluded(&self) -> &[T::Item] {
+        &self.excluded
+    }
+
+    /// Returns a copy of the blacklist, in the order the objects have been added, so it can be saved and later given back to `import_blacklist`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::filter::Exclude;
+    /// 
+    /// let iter = Exclude::with_blacklist(0..10, vec![2, 5]);
+    /// let saved = iter.export_blacklist();
+    /// 
+    /// let mut restored = Exclude::new(0..6);
+    /// restored.import_blacklist(saved);
+    /// assert_eq!(restored.collect::<Vec<i32>>(), vec![0, 1, 3, 4]);
+    /// ```
+    pub fn export_blacklist(&self) -> Vec<T::Item>
+    where
+        T::Item: Clone,
+    {
+        self.excluded.clone()
+    }
+
+    /// Adds each object yielded by `blacklist` to the blacklist, skipping the ones already inside just like `exclude` does
+    pub fn import_blacklist<I: IntoIterator<Item = T::Item>>(&mut self, blacklist: I) {
+        for new in blacklist {
+            self.exclude(new);
         }
     }
 
@@ -57,6 +135,111 @@ where
         self.excluded.push(new);
     }
 
+    /// Adds an owned copy of the borrowed object passed as arguments to the blacklist, only if it is not already inside the blacklist. It avoids allocating an object just to find that it is already blacklisted
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::filter::Exclude;
+    /// 
+    /// let names = vec![String::from("foo"), String::from("bar")];
+    /// let mut iter = Exclude::new(names.into_iter());
+    /// iter.exclude_borrowed("foo");
+    /// 
+    /// assert!(iter.is_excluded("foo"));
+    /// assert_eq!(iter.collect::<Vec<String>>(), vec![String::from("bar")]);
+    /// ```
+    /// 
+    /// # Notes
+    /// `T::Item` must be borrowable as `Q` and `Q` must be convertible to `T::Item` with `ToOwned`, like `String` and `str`. Just like with `HashMap`, the comparisons between the borrowed forms must give the same results as the comparisons between the owned ones
+    pub fn exclude_borrowed<Q>(&mut self, new: &Q)
+    where
+        T::Item: Borrow<Q>,
+        Q: ?Sized + PartialEq + ToOwned<Owned = T::Item>,
+    {
+        if !self.is_excluded(new) {
+            self.force_exclude(new.to_owned());
+        }
+    }
+
+    /// Removes the object passed as arguments from the blacklist, and returns `true` if it was inside. All its occurrences are removed, even the ones pushed by `force_exclude`
+    /// 
+    /// # Notes
+    /// The object can be passed in any form `T::Item` can be borrowed as, for example a `&str` for a blacklist of `String`s
+    pub fn remove<Q>(&mut self, item: &Q) -> bool
+    where
+        T::Item: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        let len = self.excluded.len();
+        self.excluded.retain(|x| x.borrow() != item);
+
+        self.excluded.len() != len
+    }
+
+    /// Empties the blacklist, so every object is yielded
+    pub fn clear_blacklist(&mut self) {
+        self.excluded.clear();
+    }
+
+    /// Returns `true` if the object passed as arguments is inside the blacklist
+    /// 
+    /// # Notes
+    /// The object can be passed in any form `T::Item` can be borrowed as, for example a `&str` for a blacklist of `String`s
+    pub fn is_excluded<Q>(&self, item: &Q) -> bool
+    where
+        T::Item: Borrow<Q>,
+        Q: ?Sized + PartialEq,
+    {
+        self.excluded.iter().any(|x| x.borrow() == item)
+    }
+
+    /// Starts tracking the distinct values yielded, which can then be counted with `distinct_count`. Calling it while already tracking does nothing
+    /// 
+    /// # Notes
+    /// This mode is opt-in because all the distinct values yielded are cloned into a `HashSet`, which requires `T::Item` to implement `Hash` and `Eq` and costs some memory
+    #[cfg(feature = "std")]
+    pub fn track_distinct(&mut self)
+    where
+        T::Item: Hash + Eq + Clone,
+    {
+        if self.distinct.is_none() {
+            self.distinct = Some(DistinctTracker::new());
+        }
+    }
+
+    /// Returns the number of distinct values yielded since the tracking started or since the last reset. Returns 0 if `track_distinct` has not been called
+    #[cfg(feature = "std")]
+    pub fn distinct_count(&self) -> usize {
+        self.distinct.as_ref().map_or(0, |tracker| tracker.seen.len())
+    }
+
+    /// Returns the number of objects skipped by `next` because they are blacklisted, since the creation of `self` or since the last reset
+    pub fn excluded_count(&self) -> usize {
+        self.dropped
+    }
+
+    /// Removes the duplicates of the blacklist, keeping the first occurrence of each object. You may want to call it after many calls to `force_exclude`
+    pub fn compact(&mut self) {
+        let mut i = 0;
+
+        while i < self.excluded.len() {
+            if self.excluded[..i].contains(&self.excluded[i]) {
+                self.excluded.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+
+    /// Sorts the blacklist and removes its duplicates. It is faster than `compact` but it requires `T::Item` to implement `Ord` and doesn't keep the insertion order
+    pub fn compact_sorted(&mut self)
+    where
+        T::Item: Ord,
+    {
+        self.excluded.sort();
+        self.excluded.dedup();
+    }
+
     /// Returns the iterator in use
     pub fn get_iterator(&self) -> &T {
         &self.cur
@@ -66,6 +249,61 @@ where
     pub fn get_mut_iterator(&mut self) -> &mut T {
         &mut self.cur
     }
+
+    /// Destroys `self` and returns an `ExcludeFallback` which continues with `fallback` once the iterator in use is exhausted. The blacklist applies to both iterators, and the count of excluded objects and the distinct tracking are kept and go on over both of them
+    pub fn with_fallback(self, fallback: T) -> ExcludeFallback<T> {
+        ExcludeFallback {
+            excluded: self.excluded,
+            primary: self.cur,
+            fallback,
+            on_fallback: false,
+            #[cfg(feature = "std")]
+            distinct: self.distinct,
+            dropped: self.dropped,
+        }
+    }
+
+    /// Destroys `self` and returns an `Exclude` over the remaining objects of the iterator in use in the reverse order, keeping the blacklist, the count of excluded objects and the distinct tracking
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::filter::Exclude;
+    /// 
+    /// let iter = Exclude::with_blacklist(1..6, vec![2, 5]);
+    /// assert_eq!(iter.into_reversed().collect::<Vec<i32>>(), vec![4, 3, 1]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The returned iterator wraps a `std::iter::Rev`, which can't be reset since it doesn't give access to its parent
+    pub fn into_reversed(self) -> Exclude<core::iter::Rev<T>>
+    where
+        T: DoubleEndedIterator,
+    {
+        Exclude {
+            excluded: self.excluded,
+            cur: self.cur.rev(),
+            #[cfg(feature = "std")]
+            distinct: self.distinct,
+            dropped: self.dropped,
+        }
+    }
+
+    /// Destroys `self` and returns an iterator yielding every item of the iterator in use, tagged with `true` if it would have been kept and `false` if it is blacklisted
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::filter::Exclude;
+    /// 
+    /// let array = [1, 2, 3];
+    /// let iter = Exclude::with_blacklist(array.iter().cloned(), vec![2]);
+    /// 
+    /// assert_eq!(iter.tagged().collect::<Vec<(bool, i32)>>(), vec![(true, 1), (false, 2), (true, 3)]);
+    /// ```
+    pub fn tagged(self) -> impl Iterator<Item = (bool, T::Item)> {
+        let excluded = self.excluded;
+
+        self.cur.map(move |i| (excluded.iter().position(|x| x == &i).is_none(), i))
+    }
 }
 
 impl<T> Iterator for Exclude<T>
@@ -82,13 +320,41 @@ where
                 None    => return None,
 
                 Some(i) => if self.excluded.iter().position(|x| x == &i).is_none() {
+                               #[cfg(feature = "std")]
+                               if let Some(tracker) = &mut self.distinct {
+                                   (tracker.record)(&mut tracker.seen, &i);
+                               }
+
                                return Some(i);
+                           } else {
+                               self.dropped += 1;
                 },
             }
         }
     }
 }
 
+impl<T> FusedIterator for Exclude<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq,
+{
+}
+
+// Only the length of the blacklist is printed so `T::Item` doesn't need to implement `Debug`
+impl<T> core::fmt::Debug for Exclude<T>
+where
+    T: Iterator + core::fmt::Debug,
+    T::Item: PartialEq,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Exclude")
+            .field("blacklist_len", &self.excluded.len())
+            .field("iterator", &self.cur)
+            .finish()
+    }
+}
+
 impl<T> crate::ResettableIterator for Exclude<T>
 where
     T: crate::ResettableIterator,
@@ -96,6 +362,12 @@ where
 {
     fn reset(&mut self) {
         self.cur.reset();
+        self.dropped = 0;
+
+        #[cfg(feature = "std")]
+        if let Some(tracker) = &mut self.distinct {
+            tracker.seen.clear();
+        }
     }
 }
 
@@ -119,5 +391,2429 @@ where
     }
 }
 
-#[cfg(test)]
+/// Excludes an object from iteration, just like `Exclude`, but the blacklist is stored in a `HashSet`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeHashed;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = ExcludeHashed::with_blacklist(array.iter().cloned(), vec![3, 5]);
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 4]);
+/// ```
+/// 
+/// # Notes
+/// Checking an object against the blacklist takes a constant time instead of a time proportional to the length of the blacklist, which is prefered for large blacklists. `T::Item` must implement `Hash` and `Eq` though, use `Exclude` for the types only implementing `PartialEq`
+#[derive(Clone)]
+#[cfg(feature = "std")]
+pub struct ExcludeHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq,
+{
+    excluded: HashSet<T::Item>,
+    cur: T,
+}
+
+#[cfg(feature = "std")]
+impl<T> ExcludeHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq,
+{
+    /// Returns a new object with an empty blacklist
+    pub fn new(iterator: T) -> Self {
+        ExcludeHashed {
+            cur: iterator,
+            excluded: HashSet::new(),
+        }
+    }
+
+    /// Returns a new object with the given blacklist. The duplicates of the blacklist are dropped
+    pub fn with_blacklist(iterator: T, blacklist: Vec<T::Item>) -> Self {
+        ExcludeHashed {
+            cur: iterator,
+            excluded: blacklist.into_iter().collect(),
+        }
+    }
+
+    /// Adds the object passed as arguments to the blacklist. Nothing happens if it is already inside the blacklist
+    pub fn exclude(&mut self, new: T::Item) {
+        self.excluded.insert(new);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Iterator for ExcludeHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if !self.excluded.contains(&i) {
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> FusedIterator for ExcludeHashed<T>
+where
+    T: FusedIterator,
+    T::Item: Hash + Eq,
+{
+}
+
+#[cfg(feature = "std")]
+impl<T> crate::ResettableIterator for ExcludeHashed<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Hash + Eq,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> crate::child::ChildIterator for ExcludeHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes an object from iteration, just like `Exclude`, but the blacklist is kept sorted to be searched by dichotomy
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeSorted;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = ExcludeSorted::with_sorted_blacklist(array.iter().cloned(), vec![5, 3]);
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 4]);
+/// ```
+/// 
+/// # Notes
+/// Checking an object against the blacklist takes a time proportional to the logarithm of the length of the blacklist. It is an alternative to `ExcludeHashed` for the types implementing `Ord` but not `Hash`
+#[derive(Clone)]
+pub struct ExcludeSorted<T>
+where
+    T: Iterator,
+    T::Item: Ord,
+{
+    excluded: Vec<T::Item>,
+    cur: T,
+}
+
+impl<T> ExcludeSorted<T>
+where
+    T: Iterator,
+    T::Item: Ord,
+{
+    /// Returns a new object with an empty blacklist
+    pub fn new(iterator: T) -> Self {
+        ExcludeSorted {
+            cur: iterator,
+            excluded: Vec::new(),
+        }
+    }
+
+    /// Returns a new object with the given blacklist, which is sorted and whose duplicates are dropped
+    pub fn with_sorted_blacklist(iterator: T, mut blacklist: Vec<T::Item>) -> Self {
+        blacklist.sort();
+        blacklist.dedup();
+
+        ExcludeSorted {
+            cur: iterator,
+            excluded: blacklist,
+        }
+    }
+
+    /// Inserts the object passed as arguments in the blacklist, keeping it sorted. Nothing happens if it is already inside the blacklist
+    pub fn exclude(&mut self, new: T::Item) {
+        if let Err(position) = self.excluded.binary_search(&new) {
+            self.excluded.insert(position, new);
+        }
+    }
+}
+
+impl<T> Iterator for ExcludeSorted<T>
+where
+    T: Iterator,
+    T::Item: Ord,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if self.excluded.binary_search(&i).is_err() {
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for ExcludeSorted<T>
+where
+    T: FusedIterator,
+    T::Item: Ord,
+{
+}
+
+impl<T> crate::ResettableIterator for ExcludeSorted<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Ord,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for ExcludeSorted<T>
+where
+    T: Iterator,
+    T::Item: Ord,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// The distinct values yielded by an `Exclude`. The `Hash` and `Eq` bounds required to record a value are only known by `Exclude::track_distinct`, so the recording function is stored as a pointer
+#[derive(Clone)]
+#[cfg(feature = "std")]
+struct DistinctTracker<I> {
+    seen: HashSet<I>,
+    record: fn(&mut HashSet<I>, &I),
+}
+
+#[cfg(feature = "std")]
+impl<I> DistinctTracker<I>
+where
+    I: Hash + Eq + Clone,
+{
+    fn new() -> Self {
+        Self {
+            seen: HashSet::new(),
+            record: |seen, item| {
+                if !seen.contains(item) {
+                    seen.insert(item.clone());
+                }
+            },
+        }
+    }
+}
+
+/// An `Exclude` switching to a fallback iterator once its primary iterator is exhausted. See `Exclude::with_fallback`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::Exclude;
+/// 
+/// let primary = [1, 2, 3];
+/// let fallback = [4, 5, 6];
+/// let iter = Exclude::with_blacklist(primary.iter().cloned(), vec![2, 5]);
+/// let iter = iter.with_fallback(fallback.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 4, 6]);
+/// ```
+#[derive(Clone)]
+pub struct ExcludeFallback<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    excluded: Vec<T::Item>,
+    primary: T,
+    fallback: T,
+    on_fallback: bool,
+    #[cfg(feature = "std")]
+    distinct: Option<DistinctTracker<T::Item>>,
+    dropped: usize,
+}
+
+impl<T> ExcludeFallback<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    /// Returns `true` if the primary iterator is exhausted and the items now come from the fallback one
+    pub fn is_on_fallback(&self) -> bool {
+        self.on_fallback
+    }
+
+    /// Returns the primary iterator
+    pub fn get_primary(&self) -> &T {
+        &self.primary
+    }
+
+    /// Returns the fallback iterator
+    pub fn get_fallback(&self) -> &T {
+        &self.fallback
+    }
+
+    /// Returns the number of distinct values yielded by both iterators since the tracking started on the `Exclude` or since the last reset. Returns 0 if `Exclude::track_distinct` has not been called
+    #[cfg(feature = "std")]
+    pub fn distinct_count(&self) -> usize {
+        self.distinct.as_ref().map_or(0, |tracker| tracker.seen.len())
+    }
+
+    /// Returns the number of objects skipped by `next` because they are blacklisted, the ones skipped by the `Exclude` included, since its creation or since the last reset
+    pub fn excluded_count(&self) -> usize {
+        self.dropped
+    }
+}
+
+impl<T> Iterator for ExcludeFallback<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = if self.on_fallback {
+                self.fallback.next()?
+            } else {
+                match self.primary.next() {
+                    Some(i) => i,
+                    None    => {
+                        self.on_fallback = true;
+                        continue;
+                    },
+                }
+            };
+
+            if self.excluded.iter().position(|x| x == &item).is_none() {
+                #[cfg(feature = "std")]
+                if let Some(tracker) = &mut self.distinct {
+                    (tracker.record)(&mut tracker.seen, &item);
+                }
+
+                return Some(item);
+            }
+
+            self.dropped += 1;
+        }
+    }
+}
+
+// Once the primary iterator is exhausted, only the fallback one is used
+impl<T> FusedIterator for ExcludeFallback<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq,
+{
+}
+
+impl<T> crate::ResettableIterator for ExcludeFallback<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: PartialEq,
+{
+    fn reset(&mut self) {
+        self.primary.reset();
+        self.fallback.reset();
+        self.on_fallback = false;
+        self.dropped = 0;
+
+        #[cfg(feature = "std")]
+        if let Some(tracker) = &mut self.distinct {
+            tracker.seen.clear();
+        }
+    }
+}
+
+/// The parent is the primary iterator, so `release_parent` drops the fallback one
+impl<T> crate::child::ChildIterator for ExcludeFallback<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.primary
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.primary
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.primary
+    }
+}
+
+/// Yields each item alongside its rank among the items already seen, that is the number of previous items strictly lesser than it
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::RankAgainstSeen;
+/// 
+/// let array = [3, 1, 2, 5];
+/// let iter = RankAgainstSeen::new(array.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<(i32, usize)>>(), vec![(3, 0), (1, 0), (2, 1), (5, 3)]);
+/// ```
+/// 
+/// # Notes
+/// The seen items are kept in a sorted `Vec`, which means the rank is found in O(log n) but the insertion may move the greater items
+#[derive(Clone)]
+pub struct RankAgainstSeen<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    seen: Vec<T::Item>,
+    cur: T,
+}
+
+impl<T> RankAgainstSeen<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    /// Returns a new object with no item seen yet
+    pub fn new(iterator: T) -> Self {
+        Self {
+            cur: iterator,
+            seen: Vec::new(),
+        }
+    }
+}
+
+impl<T> Iterator for RankAgainstSeen<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    type Item = (T::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.cur.next()?;
+        let rank = self.seen.partition_point(|x| x < &item);
+        self.seen.insert(rank, item.clone());
+
+        Some((item, rank))
+    }
+}
+
+impl<T> crate::ResettableIterator for RankAgainstSeen<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Ord + Clone,
+{
+    fn reset(&mut self) {
+        self.seen.clear();
+        self.cur.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for RankAgainstSeen<T>
+where
+    T: Iterator,
+    T::Item: Ord + Clone,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes the items further than `k` standard deviations from the mean of the stream
+/// 
+/// # Notes
+/// The mean and the standard deviation are computed eagerly by a first full pass over the parent, which is then reset. That's why the parent must implement `ResettableIterator`
+#[cfg(feature = "std")]
+pub struct ExcludeOutliers<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Into<f64> + Copy,
+{
+    cur: T,
+    k: f64,
+    mean: f64,
+    std_dev: f64,
+}
+
+#[cfg(feature = "std")]
+impl<T> ExcludeOutliers<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Into<f64> + Copy,
+{
+    /// Returns a new object excluding the items further than `k` standard deviations from the mean. The parent is reset and fully consumed once to compute the statistics
+    pub fn new(iterator: T, k: f64) -> Self {
+        let mut returned = Self {
+            cur: iterator,
+            k,
+            mean: 0.0,
+            std_dev: 0.0,
+        };
+
+        returned.compute_stats();
+        returned
+    }
+
+    /// Returns the mean of the stream
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the stream
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    /// Runs the first pass over the parent and resets it
+    fn compute_stats(&mut self) {
+        self.cur.reset();
+
+        let mut count = 0;
+        let mut sum = 0.0;
+        let mut square_sum = 0.0;
+        for i in self.cur.by_ref() {
+            let value: f64 = i.into();
+
+            count += 1;
+            sum += value;
+            square_sum += value * value;
+        }
+
+        if count != 0 {
+            self.mean = sum / count as f64;
+            self.std_dev = (square_sum / count as f64 - self.mean * self.mean).max(0.0).sqrt();
+        } else {
+            self.mean = 0.0;
+            self.std_dev = 0.0;
+        }
+
+        self.cur.reset();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Iterator for ExcludeOutliers<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Into<f64> + Copy,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+            let value: f64 = i.into();
+
+            if (value - self.mean).abs() <= self.k * self.std_dev {
+                return Some(i);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> crate::ResettableIterator for ExcludeOutliers<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Into<f64> + Copy,
+{
+    fn reset(&mut self) {
+        self.compute_stats();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> crate::child::ChildIterator for ExcludeOutliers<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Into<f64> + Copy,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes every item produced by a second iterator, computing `A \ B` as a stream
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeFrom;
+/// 
+/// let primary = [1, 2, 3, 4, 5];
+/// let excluder = [4, 2, 7];
+/// let iter = ExcludeFrom::new(primary.iter().cloned(), excluder.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 5]);
+/// ```
+/// 
+/// # Notes
+/// The excluder is fully drained into a `HashSet` on the first call to `next`, so it must be finite
+#[cfg(feature = "std")]
+pub struct ExcludeFrom<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Hash + Eq,
+{
+    cur: A,
+    excluder: B,
+    excluded: Option<HashSet<A::Item>>,
+}
+
+#[cfg(feature = "std")]
+impl<A, B> ExcludeFrom<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Hash + Eq,
+{
+    /// Returns a new object excluding from `primary` the items produced by `excluder`
+    pub fn new(primary: A, excluder: B) -> Self {
+        Self {
+            cur: primary,
+            excluder,
+            excluded: None,
+        }
+    }
+
+    /// Returns the iterator producing the excluded items
+    pub fn get_excluder(&self) -> &B {
+        &self.excluder
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> Iterator for ExcludeFrom<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Hash + Eq,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let excluder = &mut self.excluder;
+        let excluded = self.excluded.get_or_insert_with(|| excluder.collect());
+
+        loop {
+            let i = self.cur.next()?;
+
+            if !excluded.contains(&i) {
+                return Some(i);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> crate::ResettableIterator for ExcludeFrom<A, B>
+where
+    A: crate::ResettableIterator,
+    B: crate::ResettableIterator<Item = A::Item>,
+    A::Item: Hash + Eq,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.excluder.reset();
+        self.excluded = None;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A, B> crate::child::ChildIterator for ExcludeFrom<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    A::Item: Hash + Eq,
+{
+    type Parent = A;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Keeps only the objects of a whitelist during iteration. It is the opposite of `Exclude`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::Include;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// let iter = array.iter().cloned();
+/// // The iterator will only yield the values 2 and 4
+/// let iter = Include::with_whitelist(iter, vec![2, 4]);
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 4]);
+/// ```
+#[derive(Clone)]
+pub struct Include<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    included: Vec<T::Item>,
+    cur: T,
+}
+
+impl<T> Include<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    /// Returns a new object with an empty whitelist, which yields nothing until some objects are included
+    pub fn new(iterator: T) -> Self {
+        Include {
+            cur: iterator,
+            included: Vec::new(),
+        }
+    }
+
+    /// Returns a new object with the given whitelist
+    pub fn with_whitelist(iterator: T, whitelist: Vec<T::Item>) -> Self {
+        Include {
+            cur: iterator,
+            included: whitelist,
+        }
+    }
+
+    /// Adds the object passed as arguments to the whitelist. It will be added only if it is not already inside the whitelist
+    pub fn include(&mut self, new: T::Item) {
+        if !self.included.contains(&new) {
+            self.included.push(new);
+        }
+    }
+}
+
+impl<T> Iterator for Include<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if self.included.contains(&i) {
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Include<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq,
+{
+}
+
+impl<T> crate::ResettableIterator for Include<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: PartialEq,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for Include<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Keeps only the objects of an iterator inside a set of allowed objects, which can be edited between two iterations
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::Retain;
+/// 
+/// let mut iter = Retain::from_set(1..=5, vec![2, 4]);
+/// iter.allow(5);
+/// iter.disallow(&2);
+/// 
+/// assert!(iter.is_allowed(&4));
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![4, 5]);
+/// ```
+/// 
+/// It is the intersection of the objects yielded and of the allowed set. Unlike `Include`, objects can also be removed from this set
+#[derive(Clone)]
+pub struct Retain<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    allowed: Vec<T::Item>,
+    cur: T,
+}
+
+impl<T> Retain<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    /// Returns a new object allowing the objects of `set` only. The duplicates of `set` are ignored
+    pub fn from_set(iterator: T, set: Vec<T::Item>) -> Self {
+        let mut retain = Retain {
+            allowed: Vec::with_capacity(set.len()),
+            cur: iterator,
+        };
+
+        for item in set {
+            retain.allow(item);
+        }
+
+        retain
+    }
+
+    /// Adds the object passed as arguments to the allowed set. It will be added only if it is not already inside the set
+    pub fn allow(&mut self, item: T::Item) {
+        if !self.allowed.contains(&item) {
+            self.allowed.push(item);
+        }
+    }
+
+    /// Removes the object passed as arguments from the allowed set, and returns `true` if it was inside
+    pub fn disallow(&mut self, item: &T::Item) -> bool {
+        match self.allowed.iter().position(|x| x == item) {
+            Some(position) => {
+                self.allowed.swap_remove(position);
+                true
+            },
+
+            None => false,
+        }
+    }
+
+    /// Returns `true` if the object passed as arguments is inside the allowed set
+    pub fn is_allowed(&self, item: &T::Item) -> bool {
+        self.allowed.contains(item)
+    }
+}
+
+impl<T> Iterator for Retain<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if self.allowed.contains(&i) {
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Retain<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq,
+{
+}
+
+impl<T> crate::ResettableIterator for Retain<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: PartialEq,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T> crate::child::ChildIterator for Retain<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes the objects matching a predicate from iteration. Unlike `Exclude`, the objects don't need to be comparable
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeIf;
+/// 
+/// // Drops the even numbers
+/// let iter = ExcludeIf::new(1..=6, |x: &i32| x % 2 == 0);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 5]);
+/// ```
+#[derive(Clone)]
+pub struct ExcludeIf<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    cur: T,
+    predicate: P,
+}
+
+impl<T, P> ExcludeIf<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    /// Returns a new object excluding the objects for which `predicate` returns `true`
+    pub fn new(iterator: T, predicate: P) -> Self {
+        ExcludeIf {
+            cur: iterator,
+            predicate,
+        }
+    }
+}
+
+impl<T, P> Iterator for ExcludeIf<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if !(self.predicate)(&i) {
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+impl<T, P> FusedIterator for ExcludeIf<T, P>
+where
+    T: FusedIterator,
+    P: FnMut(&T::Item) -> bool,
+{
+}
+
+impl<T, P> crate::ResettableIterator for ExcludeIf<T, P>
+where
+    T: crate::ResettableIterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T, P> crate::child::ChildIterator for ExcludeIf<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes the objects whose key is inside a blacklist. The key of each object is computed by a closure, so unlike with `Exclude` the objects themselves don't need to be comparable
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeByKey;
+/// 
+/// let words = vec!["apple", "bean", "avocado", "carrot"];
+/// // Drops the words starting with an 'a'
+/// let iter = ExcludeByKey::with_blacklist(words.into_iter(), |word: &&str| word.chars().next(), vec![Some('a')]);
+/// 
+/// assert_eq!(iter.collect::<Vec<&str>>(), vec!["bean", "carrot"]);
+/// ```
+#[derive(Clone)]
+pub struct ExcludeByKey<T, K, F>
+where
+    T: Iterator,
+    K: PartialEq,
+    F: FnMut(&T::Item) -> K,
+{
+    excluded: Vec<K>,
+    cur: T,
+    key: F,
+}
+
+impl<T, K, F> ExcludeByKey<T, K, F>
+where
+    T: Iterator,
+    K: PartialEq,
+    F: FnMut(&T::Item) -> K,
+{
+    /// Returns a new object with an empty blacklist, computing the key of each object with `key`
+    pub fn new(iterator: T, key: F) -> Self {
+        Self::with_blacklist(iterator, key, Vec::new())
+    }
+
+    /// Returns a new object with the given blacklist of keys
+    pub fn with_blacklist(iterator: T, key: F, blacklist: Vec<K>) -> Self {
+        ExcludeByKey {
+            excluded: blacklist,
+            cur: iterator,
+            key,
+        }
+    }
+
+    /// Adds the key passed as arguments to the blacklist. It will be added only if it is not already inside the blacklist
+    pub fn exclude(&mut self, new: K) {
+        if !self.is_excluded(&new) {
+            self.excluded.push(new);
+        }
+    }
+
+    /// Returns `true` if the key passed as arguments is inside the blacklist
+    pub fn is_excluded(&self, key: &K) -> bool {
+        self.excluded.contains(key)
+    }
+}
+
+impl<T, K, F> Iterator for ExcludeByKey<T, K, F>
+where
+    T: Iterator,
+    K: PartialEq,
+    F: FnMut(&T::Item) -> K,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+
+            if !self.excluded.contains(&(self.key)(&i)) {
+                return Some(i);
+            }
+        }
+    }
+}
+
+impl<T, K, F> FusedIterator for ExcludeByKey<T, K, F>
+where
+    T: FusedIterator,
+    K: PartialEq,
+    F: FnMut(&T::Item) -> K,
+{
+}
+
+impl<T, K, F> crate::ResettableIterator for ExcludeByKey<T, K, F>
+where
+    T: crate::ResettableIterator,
+    K: PartialEq,
+    F: FnMut(&T::Item) -> K,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T, K, F> crate::child::ChildIterator for ExcludeByKey<T, K, F>
+where
+    T: Iterator,
+    K: PartialEq,
+    F: FnMut(&T::Item) -> K,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes the objects equal to one of a blacklist, the equality being decided by a closure. Unlike with `Exclude`, the objects don't need to implement `PartialEq`, which allows for example case-insensitive or approximate comparisons
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeWith;
+/// 
+/// let words = vec!["foo", "bar", "FOO", "Baz"];
+/// let mut iter = ExcludeWith::new(words.into_iter(), |a: &&str, b: &&str| a.eq_ignore_ascii_case(b));
+/// iter.exclude("foo");
+/// 
+/// assert_eq!(iter.collect::<Vec<&str>>(), vec!["bar", "Baz"]);
+/// ```
+#[derive(Clone)]
+pub struct ExcludeWith<T, C>
+where
+    T: Iterator,
+    C: Fn(&T::Item, &T::Item) -> bool,
+{
+    excluded: Vec<T::Item>,
+    cur: T,
+    eq: C,
+}
+
+impl<T, C> ExcludeWith<T, C>
+where
+    T: Iterator,
+    C: Fn(&T::Item, &T::Item) -> bool,
+{
+    /// Returns a new object with an empty blacklist, comparing the objects with `eq`
+    pub fn new(iterator: T, eq: C) -> Self {
+        Self::with_blacklist(iterator, eq, Vec::new())
+    }
+
+    /// Returns a new object with the given blacklist
+    pub fn with_blacklist(iterator: T, eq: C, blacklist: Vec<T::Item>) -> Self {
+        ExcludeWith {
+            excluded: blacklist,
+            cur: iterator,
+            eq,
+        }
+    }
+
+    /// Adds the object passed as arguments to the blacklist. It will be added only if no equal object, according to the comparator, is already inside the blacklist
+    pub fn exclude(&mut self, new: T::Item) {
+        if !self.is_excluded(&new) {
+            self.excluded.push(new);
+        }
+    }
+
+    /// Returns `true` if an object equal to the one passed as arguments, according to the comparator, is inside the blacklist
+    pub fn is_excluded(&self, object: &T::Item) -> bool {
+        self.excluded.iter().any(|excluded| (self.eq)(excluded, object))
+    }
+}
+
+impl<T, C> Iterator for ExcludeWith<T, C>
+where
+    T: Iterator,
+    C: Fn(&T::Item, &T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+
+            if !self.is_excluded(&i) {
+                return Some(i);
+            }
+        }
+    }
+}
+
+impl<T, C> FusedIterator for ExcludeWith<T, C>
+where
+    T: FusedIterator,
+    C: Fn(&T::Item, &T::Item) -> bool,
+{
+}
+
+impl<T, C> crate::ResettableIterator for ExcludeWith<T, C>
+where
+    T: crate::ResettableIterator,
+    C: Fn(&T::Item, &T::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+    }
+}
+
+impl<T, C> crate::child::ChildIterator for ExcludeWith<T, C>
+where
+    T: Iterator,
+    C: Fn(&T::Item, &T::Item) -> bool,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes only the first occurrences of some objects from iteration, each blacklisted object being associated with the number of occurrences to drop
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeN;
+/// 
+/// // Skips the first two 3s but keeps the later ones
+/// let iter = ExcludeN::with_limits(vec![3, 1, 3, 3, 3].into_iter(), vec![(3, 2)]);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 3]);
+/// ```
+/// 
+/// Once the budget of an object is spent, its later occurrences are yielded. Resetting the iterator restores the original budgets
+#[derive(Clone)]
+pub struct ExcludeN<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    limits: Vec<(T::Item, usize)>,
+    remaining: Vec<usize>,
+    cur: T,
+}
+
+impl<T> ExcludeN<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    /// Returns a new object with an empty blacklist
+    pub fn new(iterator: T) -> Self {
+        ExcludeN {
+            limits: Vec::new(),
+            remaining: Vec::new(),
+            cur: iterator,
+        }
+    }
+
+    /// Returns a new object dropping the first `count` occurrences of `object` for each `(object, count)` of `limits`
+    pub fn with_limits(iterator: T, limits: Vec<(T::Item, usize)>) -> Self {
+        let mut iterator = Self::new(iterator);
+        for (object, count) in limits {
+            iterator.exclude(object, count);
+        }
+
+        iterator
+    }
+
+    /// Drops `count` more occurrences of `new`. The count is added to the current budget of `new` if it is already blacklisted
+    pub fn exclude(&mut self, new: T::Item, count: usize) {
+        match self.limits.iter().position(|(object, _)| object == &new) {
+            Some(position) => {
+                self.limits[position].1 += count;
+                self.remaining[position] += count;
+            },
+
+            None => {
+                self.limits.push((new, count));
+                self.remaining.push(count);
+            },
+        }
+    }
+
+    /// Returns the number of occurrences of `object` still to drop
+    pub fn remaining(&self, object: &T::Item) -> usize {
+        self.limits.iter().position(|(x, _)| x == object).map_or(0, |position| self.remaining[position])
+    }
+}
+
+impl<T> Iterator for ExcludeN<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+
+            match self.limits.iter().position(|(object, _)| object == &i) {
+                Some(position) if self.remaining[position] > 0 => self.remaining[position] -= 1,
+                _                                               => return Some(i),
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for ExcludeN<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq,
+{
+}
+
+impl<T> crate::ResettableIterator for ExcludeN<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: PartialEq,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+
+        for (remaining, (_, count)) in self.remaining.iter_mut().zip(&self.limits) {
+            *remaining = *count;
+        }
+    }
+}
+
+impl<T> crate::child::ChildIterator for ExcludeN<T>
+where
+    T: Iterator,
+    T::Item: PartialEq,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Skips the objects already yielded, keeping the order in which they are first met
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::Unique;
+/// 
+/// let array = [1, 2, 2, 3, 1, 4];
+/// let iter = Unique::new(array.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+/// ```
+/// 
+/// # Notes
+/// A clone of each object yielded is kept and searched linearly. `UniqueHashed` is prefered for the types implementing `Hash` and `Eq`
+#[derive(Clone)]
+pub struct Unique<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    seen: Vec<T::Item>,
+    cur: T,
+}
+
+impl<T> Unique<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    /// Returns a new object yielding the objects of `iterator` which have not been yielded yet
+    pub fn new(iterator: T) -> Self {
+        Unique {
+            cur: iterator,
+            seen: Vec::new(),
+        }
+    }
+}
+
+impl<T> Iterator for Unique<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if !self.seen.contains(&i) {
+                               self.seen.push(i.clone());
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Unique<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq + Clone,
+{
+}
+
+impl<T> crate::ResettableIterator for Unique<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: PartialEq + Clone,
+{
+    /// Resets the parent iterator and forgets the objects already yielded
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.seen.clear();
+    }
+}
+
+impl<T> crate::child::ChildIterator for Unique<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Skips the objects already yielded just like `Unique`, but the objects already yielded are stored in a `HashSet`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::UniqueHashed;
+/// 
+/// let array = [1, 2, 2, 3, 1, 4];
+/// let iter = UniqueHashed::new(array.iter().cloned());
+/// 
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+/// ```
+#[derive(Clone)]
+#[cfg(feature = "std")]
+pub struct UniqueHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq + Clone,
+{
+    seen: HashSet<T::Item>,
+    cur: T,
+}
+
+#[cfg(feature = "std")]
+impl<T> UniqueHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq + Clone,
+{
+    /// Returns a new object yielding the objects of `iterator` which have not been yielded yet
+    pub fn new(iterator: T) -> Self {
+        UniqueHashed {
+            cur: iterator,
+            seen: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Iterator for UniqueHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq + Clone,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.cur.next() {
+                // Happens when the iterator is fully consumed
+                None    => return None,
+
+                Some(i) => if !self.seen.contains(&i) {
+                               self.seen.insert(i.clone());
+                               return Some(i);
+                },
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> FusedIterator for UniqueHashed<T>
+where
+    T: FusedIterator,
+    T::Item: Hash + Eq + Clone,
+{
+}
+
+#[cfg(feature = "std")]
+impl<T> crate::ResettableIterator for UniqueHashed<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: Hash + Eq + Clone,
+{
+    /// Resets the parent iterator and forgets the objects already yielded
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.seen.clear();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> crate::child::ChildIterator for UniqueHashed<T>
+where
+    T: Iterator,
+    T::Item: Hash + Eq + Clone,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Yields at most the `n` first objects of an iterator, like `std::iter::Take` but resettable
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::{Exclude, Limit};
+/// 
+/// let iter = Limit::new(Exclude::with_blacklist(0..10, vec![1, 2]), 3);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 3, 4]);
+/// ```
+#[derive(Clone)]
+pub struct Limit<T>
+where
+    T: Iterator,
+{
+    cur: T,
+    limit: usize,
+    taken: usize,
+}
+
+impl<T> Limit<T>
+where
+    T: Iterator,
+{
+    /// Returns a new object yielding at most the `n` first objects of `iterator`
+    pub fn new(iterator: T, n: usize) -> Self {
+        Limit {
+            cur: iterator,
+            limit: n,
+            taken: 0,
+        }
+    }
+
+    /// Returns the number of objects which can still be yielded before reaching the limit
+    pub fn remaining(&self) -> usize {
+        self.limit - self.taken
+    }
+}
+
+impl<T> Iterator for Limit<T>
+where
+    T: Iterator,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.taken == self.limit {
+            return None;
+        }
+
+        let returned = self.cur.next()?;
+        self.taken += 1;
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.cur.size_hint();
+        let remaining = self.remaining();
+
+        (low.min(remaining), Some(high.map_or(remaining, |high| high.min(remaining))))
+    }
+}
+
+impl<T> FusedIterator for Limit<T>
+where
+    T: FusedIterator,
+{
+}
+
+impl<T> crate::ResettableIterator for Limit<T>
+where
+    T: crate::ResettableIterator,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.taken = 0;
+    }
+}
+
+impl<T> crate::child::ChildIterator for Limit<T>
+where
+    T: Iterator,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Yields the objects of an iterator as long as they match a predicate, like `std::iter::TakeWhile` but resettable
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::TakeWhileReset;
+/// 
+/// let iter = TakeWhileReset::new(1..10, |x: &i32| x * x < 20);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+/// ```
+/// 
+/// The first object not matching the predicate is consumed but not yielded, and nothing is yielded after it until the iterator is reset
+#[derive(Clone)]
+pub struct TakeWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    cur: T,
+    predicate: P,
+    done: bool,
+}
+
+impl<T, P> TakeWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    /// Returns a new object yielding the objects of `iterator` until `predicate` returns `false`
+    pub fn new(iterator: T, predicate: P) -> Self {
+        TakeWhileReset {
+            cur: iterator,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+impl<T, P> Iterator for TakeWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let i = self.cur.next()?;
+        if (self.predicate)(&i) {
+            Some(i)
+        } else {
+            self.done = true;
+            None
+        }
+    }
+}
+
+impl<T, P> FusedIterator for TakeWhileReset<T, P>
+where
+    T: FusedIterator,
+    P: FnMut(&T::Item) -> bool,
+{
+}
+
+impl<T, P> crate::ResettableIterator for TakeWhileReset<T, P>
+where
+    T: crate::ResettableIterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.done = false;
+    }
+}
+
+impl<T, P> crate::child::ChildIterator for TakeWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Skips the objects of an iterator as long as they match a predicate, then yields all the following ones, like `std::iter::SkipWhile` but resettable
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::SkipWhileReset;
+/// 
+/// let iter = SkipWhileReset::new(vec![1, 2, 5, 1, 6].into_iter(), |x: &i32| *x < 3);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![5, 1, 6]);
+/// ```
+#[derive(Clone)]
+pub struct SkipWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    cur: T,
+    predicate: P,
+    skipping: bool,
+}
+
+impl<T, P> SkipWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    /// Returns a new object skipping the objects of `iterator` until `predicate` returns `false`
+    pub fn new(iterator: T, predicate: P) -> Self {
+        SkipWhileReset {
+            cur: iterator,
+            predicate,
+            skipping: true,
+        }
+    }
+}
+
+impl<T, P> Iterator for SkipWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+
+            if !self.skipping || !(self.predicate)(&i) {
+                self.skipping = false;
+                return Some(i);
+            }
+        }
+    }
+}
+
+impl<T, P> FusedIterator for SkipWhileReset<T, P>
+where
+    T: FusedIterator,
+    P: FnMut(&T::Item) -> bool,
+{
+}
+
+impl<T, P> crate::ResettableIterator for SkipWhileReset<T, P>
+where
+    T: crate::ResettableIterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.skipping = true;
+    }
+}
+
+impl<T, P> crate::child::ChildIterator for SkipWhileReset<T, P>
+where
+    T: Iterator,
+    P: FnMut(&T::Item) -> bool,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Excludes the objects at some positions of the parent iterator, whatever their values are
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ExcludeIndices;
+/// 
+/// let iter = ExcludeIndices::new(vec![10, 20, 30, 40].into_iter(), vec![0, 2]);
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![20, 40]);
+/// ```
+/// 
+/// # Notes
+/// The positions are the 0-based indexes of the objects in the parent iterator, counted since its creation or its last reset, the excluded objects included
+#[derive(Clone)]
+pub struct ExcludeIndices<T> {
+    cur: T,
+    excluded: Vec<usize>,
+    position: usize,
+}
+
+impl<T: Iterator> ExcludeIndices<T> {
+    /// Returns a new object excluding the objects of `iterator` whose positions are inside `indices`
+    pub fn new(iterator: T, indices: Vec<usize>) -> Self {
+        ExcludeIndices {
+            cur: iterator,
+            excluded: indices,
+            position: 0,
+        }
+    }
+
+    /// Adds the position passed as arguments to the blacklist. It will be added only if it is not already inside the blacklist
+    pub fn exclude(&mut self, index: usize) {
+        if !self.is_excluded(index) {
+            self.excluded.push(index);
+        }
+    }
+
+    /// Returns `true` if the position passed as arguments is inside the blacklist
+    pub fn is_excluded(&self, index: usize) -> bool {
+        self.excluded.contains(&index)
+    }
+
+    /// Returns the position in the parent iterator of the next object it will yield
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl<T: Iterator> Iterator for ExcludeIndices<T> {
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+            let position = self.position;
+            self.position += 1;
+
+            if !self.is_excluded(position) {
+                return Some(i);
+            }
+        }
+    }
+}
+
+impl<T: FusedIterator> FusedIterator for ExcludeIndices<T> {}
+
+impl<T: crate::ResettableIterator> crate::ResettableIterator for ExcludeIndices<T> {
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.position = 0;
+    }
+}
+
+impl<T: Iterator> crate::child::ChildIterator for ExcludeIndices<T> {
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Drops the objects equal to the previous one, like `Vec::dedup` does. Unlike `Unique`, only the last object yielded is remembered, so the memory used doesn't grow
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::DedupAdjacent;
+/// 
+/// let iter = DedupAdjacent::new(vec![1, 1, 2, 2, 2, 3, 1].into_iter());
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 1]);
+/// ```
+#[derive(Clone)]
+pub struct DedupAdjacent<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    cur: T,
+    last: Option<T::Item>,
+}
+
+impl<T> DedupAdjacent<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    /// Returns a new object dropping the consecutive duplicates of `iterator`
+    pub fn new(iterator: T) -> Self {
+        DedupAdjacent {
+            cur: iterator,
+            last: None,
+        }
+    }
+}
+
+impl<T> Iterator for DedupAdjacent<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+
+            if self.last.as_ref() != Some(&i) {
+                self.last = Some(i.clone());
+                return Some(i);
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for DedupAdjacent<T>
+where
+    T: FusedIterator,
+    T::Item: PartialEq + Clone,
+{
+}
+
+impl<T> crate::ResettableIterator for DedupAdjacent<T>
+where
+    T: crate::ResettableIterator,
+    T::Item: PartialEq + Clone,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.last = None;
+    }
+}
+
+impl<T> crate::child::ChildIterator for DedupAdjacent<T>
+where
+    T: Iterator,
+    T::Item: PartialEq + Clone,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Decides whether to yield each object with a closure which can update a state, like `std::iter::Iterator::scan` does. Resetting the iterator gives the state back its initial value
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::ScanFilter;
+/// 
+/// // Drops the objects until the running sum exceeds 5
+/// let iter = ScanFilter::new(1..=6, 0, |sum: &mut i32, x: &i32| {
+///     *sum += x;
+///     *sum > 5
+/// });
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![3, 4, 5, 6]);
+/// ```
+#[derive(Clone)]
+pub struct ScanFilter<T, S, F>
+where
+    T: Iterator,
+    S: Clone,
+    F: FnMut(&mut S, &T::Item) -> bool,
+{
+    cur: T,
+    initial: S,
+    state: S,
+    callback: F,
+}
+
+impl<T, S, F> ScanFilter<T, S, F>
+where
+    T: Iterator,
+    S: Clone,
+    F: FnMut(&mut S, &T::Item) -> bool,
+{
+    /// Returns a new object yielding the objects for which `callback` returns `true`, the state starting at `initial`
+    pub fn new(iterator: T, initial: S, callback: F) -> Self {
+        ScanFilter {
+            cur: iterator,
+            state: initial.clone(),
+            initial,
+            callback,
+        }
+    }
+
+    /// Returns the current value of the state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<T, S, F> Iterator for ScanFilter<T, S, F>
+where
+    T: Iterator,
+    S: Clone,
+    F: FnMut(&mut S, &T::Item) -> bool,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let i = self.cur.next()?;
+
+            if (self.callback)(&mut self.state, &i) {
+                return Some(i);
+            }
+        }
+    }
+}
+
+impl<T, S, F> FusedIterator for ScanFilter<T, S, F>
+where
+    T: FusedIterator,
+    S: Clone,
+    F: FnMut(&mut S, &T::Item) -> bool,
+{
+}
+
+impl<T, S, F> crate::ResettableIterator for ScanFilter<T, S, F>
+where
+    T: crate::ResettableIterator,
+    S: Clone,
+    F: FnMut(&mut S, &T::Item) -> bool,
+{
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.state = self.initial.clone();
+    }
+}
+
+impl<T, S, F> crate::child::ChildIterator for ScanFilter<T, S, F>
+where
+    T: Iterator,
+    S: Clone,
+    F: FnMut(&mut S, &T::Item) -> bool,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Yields a uniform random sample of at most `k` objects of an iterator, using reservoir sampling. The randomness comes from a small deterministic generator (SplitMix64) seeded by the user, so a given seed always gives the same sample
+/// 
+/// The parent is fully consumed on the first call to `next`, but only `k` objects are kept in memory at any time
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::Reservoir;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let mut iter = Reservoir::new(iterators_collection::reset::SliceResetIter::new(&[1, 2, 3, 4, 5, 6, 7, 8]), 3, 42);
+/// let sample = iter.by_ref().collect::<Vec<&i32>>();
+/// assert_eq!(sample.len(), 3);
+/// 
+/// // Same seed, same sample
+/// iter.reset();
+/// assert_eq!(iter.collect::<Vec<&i32>>(), sample);
+/// ```
+/// 
+/// # Notes
+/// The sampled objects are yielded in the order of the reservoir, not in their original order. The generator is not cryptographically secure, and the index drawing has a negligible modulo bias
+pub struct Reservoir<T>
+where
+    T: Iterator,
+{
+    cur: T,
+    k: usize,
+    seed: u64,
+    sample: Option<alloc::vec::IntoIter<T::Item>>,
+}
+
+impl<T> Reservoir<T>
+where
+    T: Iterator,
+{
+    /// Returns a new object sampling at most `k` objects of `iterator` with a generator seeded by `seed`
+    pub fn new(iterator: T, k: usize, seed: u64) -> Self {
+        Reservoir {
+            cur: iterator,
+            k,
+            seed,
+            sample: None,
+        }
+    }
+
+    /// Returns the seed used to sample
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Consumes the parent and fills the reservoir
+    fn fill(&mut self) -> Vec<T::Item> {
+        let mut reservoir = Vec::with_capacity(self.k);
+        let mut state = self.seed;
+
+        for (index, i) in self.cur.by_ref().enumerate() {
+            if index < self.k {
+                reservoir.push(i);
+                continue;
+            }
+
+            let random = crate::share::splitmix64(state);
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+            let slot = (random % (index as u64 + 1)) as usize;
+            if slot < self.k {
+                reservoir[slot] = i;
+            }
+        }
+
+        reservoir
+    }
+}
+
+impl<T> Iterator for Reservoir<T>
+where
+    T: Iterator,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sample.is_none() {
+            self.sample = Some(self.fill().into_iter());
+        }
+
+        self.sample.as_mut()?.next()
+    }
+}
+
+impl<T> crate::ResettableIterator for Reservoir<T>
+where
+    T: crate::ResettableIterator,
+{
+    /// Resets the parent and drops the current sample. The next call to `next` samples again with the original seed
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.sample = None;
+    }
+}
+
+impl<T> crate::child::ChildIterator for Reservoir<T>
+where
+    T: Iterator,
+{
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Yields the objects of an iterator together with their position in its output, starting at 0. Unlike `core::iter::Enumerate`, it can be reset and gives access to its parent, so the positions can be taken after a filter of this crate
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::{EnumerateReset, ExcludeIf};
+/// 
+/// let iter = EnumerateReset::new(ExcludeIf::new(1..=5, |x: &i32| x % 2 == 0));
+/// assert_eq!(iter.collect::<Vec<(usize, i32)>>(), vec![(0, 1), (1, 3), (2, 5)]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnumerateReset<T> {
+    cur: T,
+    passed: usize,
+}
+
+impl<T: Iterator> EnumerateReset<T> {
+    /// Returns a new object numbering the objects of `iterator`
+    pub fn new(iterator: T) -> Self {
+        EnumerateReset {
+            cur: iterator,
+            passed: 0,
+        }
+    }
+
+    /// Returns the number of objects yielded so far, which is the position the next one will get
+    pub fn passed(&self) -> usize {
+        self.passed
+    }
+}
+
+impl<T: Iterator> Iterator for EnumerateReset<T> {
+    type Item = (usize, T::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.cur.next()?;
+        let position = self.passed;
+        self.passed += 1;
+
+        Some((position, i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cur.size_hint()
+    }
+}
+
+impl<T: ExactSizeIterator> ExactSizeIterator for EnumerateReset<T> {}
+
+impl<T: FusedIterator> FusedIterator for EnumerateReset<T> {}
+
+impl<T: crate::ResettableIterator> crate::ResettableIterator for EnumerateReset<T> {
+    /// Resets the parent and the counter, so the first object is numbered 0 again
+    fn reset(&mut self) {
+        self.cur.reset();
+        self.passed = 0;
+    }
+}
+
+impl<T: Iterator> crate::child::ChildIterator for EnumerateReset<T> {
+    type Parent = T;
+
+    fn release_parent(self) -> Self::Parent {
+        self.cur
+    }
+
+    fn get_parent_mut(&mut self) -> &mut Self::Parent {
+        &mut self.cur
+    }
+
+    fn get_parent(&self) -> &Self::Parent {
+        &self.cur
+    }
+}
+
+/// Splits the objects of an iterator into the ones satisfying a predicate and the other ones, in one pass. Unlike `Exclude` no object is lost: both sides are kept in their original order
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::filter::Partition;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let partition = Partition::new(1..=6, |x: &i32| x % 2 == 0);
+/// 
+/// let mut even = partition.matching();
+/// assert_eq!(even.by_ref().cloned().collect::<Vec<i32>>(), vec![2, 4, 6]);
+/// even.reset();
+/// assert_eq!(even.next(), Some(&2));
+/// 
+/// assert_eq!(partition.non_matching().cloned().collect::<Vec<i32>>(), vec![1, 3, 5]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Partition<T> {
+    matching: Vec<T>,
+    non_matching: Vec<T>,
+}
+
+impl<T> Partition<T> {
+    /// Consumes `iterator` and sorts its objects depending on whether `predicate` returns `true` for them
+    pub fn new<I, P>(iterator: I, mut predicate: P) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        P: FnMut(&T) -> bool,
+    {
+        let mut matching = Vec::new();
+        let mut non_matching = Vec::new();
+
+        for i in iterator {
+            if predicate(&i) {
+                matching.push(i);
+            } else {
+                non_matching.push(i);
+            }
+        }
+
+        Partition {
+            matching,
+            non_matching,
+        }
+    }
+
+    /// Returns a resettable iterator over the objects satisfying the predicate
+    pub fn matching(&self) -> crate::reset::SliceResetIter<'_, T> {
+        crate::reset::SliceResetIter::new(&self.matching)
+    }
+
+    /// Returns a resettable iterator over the objects not satisfying the predicate
+    pub fn non_matching(&self) -> crate::reset::SliceResetIter<'_, T> {
+        crate::reset::SliceResetIter::new(&self.non_matching)
+    }
+
+    /// Returns the two sides, the objects satisfying the predicate first
+    pub fn into_parts(self) -> (Vec<T>, Vec<T>) {
+        (self.matching, self.non_matching)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests;
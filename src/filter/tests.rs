@@ -1,6 +1,42 @@
 //! Unit tests for the filter module
 
 use crate::filter;
+use crate::ResettableIterator;
+
+/// A resettable iterator over a `Vec` used as a parent in the tests below
+#[derive(Clone)]
+struct Values<T> {
+    values: Vec<T>,
+    cur: usize,
+}
+
+impl<T> Values<T> {
+    fn new(values: Vec<T>) -> Self {
+        Self {
+            values,
+            cur: 0,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for Values<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let returned = self.values.get(self.cur).cloned();
+        if returned.is_some() {
+            self.cur += 1;
+        }
+
+        returned
+    }
+}
+
+impl<T: Clone> ResettableIterator for Values<T> {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
 
 #[test]
 fn exclude() {
@@ -27,3 +63,615 @@ fn exclude_construction() {
     iter2.force_exclude(3); // should be added now because of the call to `force_exclude`
     assert_eq!(iter2.excluded, vec![3, 5, 3]);
 }
+
+#[test]
+fn exclude_is_fused() {
+    let array = [1, 2, 3];
+    let mut iter = filter::Exclude::with_blacklist(array.iter().cloned(), vec![3]);
+    while iter.next().is_some() {}
+
+    for _ in 0..10 {
+        assert!(iter.next().is_none());
+    }
+}
+
+#[test]
+fn exclude_compact() {
+    let array = [1, 2, 3, 4, 5];
+    let array_iter = array.iter().cloned();
+    let mut iter = filter::Exclude::with_blacklist(array_iter, vec![5, 3, 5, 3, 5]);
+
+    iter.compact();
+    assert_eq!(iter.excluded, vec![5, 3]);
+    assert_eq!(iter.clone().collect::<Vec<i32>>(), vec![1, 2, 4]);
+
+    iter.force_exclude(1);
+    iter.force_exclude(3);
+    iter.compact_sorted();
+    assert_eq!(iter.excluded, vec![1, 3, 5]);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 4]);
+}
+
+#[test]
+fn exclude_tagged() {
+    let array = [1, 2, 3, 4, 5];
+    let array_iter = array.iter().cloned();
+    let iter = filter::Exclude::with_blacklist(array_iter, vec![3, 5]);
+
+    let tagged = iter.clone().tagged().collect::<Vec<(bool, i32)>>();
+    assert_eq!(tagged, vec![(true, 1), (true, 2), (false, 3), (true, 4), (false, 5)]);
+
+    // The kept items are the ones yielded by the `Iterator` implementation
+    let kept = tagged.into_iter().filter(|x| x.0).map(|x| x.1).collect::<Vec<i32>>();
+    assert_eq!(kept, iter.collect::<Vec<i32>>());
+}
+
+#[test]
+fn exclude_track_distinct() {
+    let values = Values::new(vec![1, 2, 2, 3, 1, 4, 4]);
+    let mut iter = filter::Exclude::with_blacklist(values, vec![4]);
+
+    iter.next();
+    assert_eq!(iter.distinct_count(), 0);
+
+    iter.track_distinct();
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.distinct_count(), 3);
+
+    iter.reset();
+    assert_eq!(iter.distinct_count(), 0);
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.distinct_count(), 3);
+}
+
+#[test]
+fn exclude_with_fallback() {
+    let primary = Values::new(vec![1, 2, 3]);
+    let fallback = Values::new(vec![3, 4, 5]);
+    let mut iter = filter::Exclude::with_blacklist(primary, vec![2, 3]).with_fallback(fallback);
+
+    assert_eq!(iter.next(), Some(1));
+    assert!(!iter.is_on_fallback());
+
+    assert_eq!(iter.next(), Some(4));
+    assert!(iter.is_on_fallback());
+    assert_eq!(iter.next(), Some(5));
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert!(!iter.is_on_fallback());
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 4, 5]);
+}
+
+#[test]
+fn exclude_with_fallback_keeps_the_counters() {
+    use crate::child::ChildIterator;
+
+    let primary = [1, 2, 1, 3];
+    let fallback = [2, 4, 1];
+    let mut iter = filter::Exclude::with_blacklist(primary.iter().cloned(), vec![2]);
+    iter.track_distinct();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.excluded_count(), 1);
+
+    let mut iter = iter.with_fallback(fallback.iter().cloned());
+    assert_eq!(iter.excluded_count(), 1);
+    assert_eq!(iter.distinct_count(), 1);
+    assert_eq!(iter.get_parent().len(), 1);
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![3, 4, 1]);
+    assert_eq!(iter.excluded_count(), 2);
+    assert_eq!(iter.distinct_count(), 3);
+
+    // Fused since both iterators are
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.release_parent().next(), None);
+}
+
+#[test]
+fn rank_against_seen() {
+    let array = [4, 4, 1, 7, 4, 0];
+    let iter = filter::RankAgainstSeen::new(array.iter().cloned());
+    let ranks = iter.map(|(_, rank)| rank).collect::<Vec<usize>>();
+
+    // Equal items are not counted
+    assert_eq!(ranks, vec![0, 0, 0, 3, 1, 0]);
+}
+
+#[test]
+fn exclude_outliers() {
+    let values = Values::new(vec![10, 12, 11, 9, 10, 40, 8]);
+    let mut iter = filter::ExcludeOutliers::new(values, 2.0);
+
+    assert_eq!(iter.mean(), 100.0 / 7.0);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![10, 12, 11, 9, 10, 8]);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![10, 12, 11, 9, 10, 8]);
+}
+
+#[test]
+fn exclude_from() {
+    let primary = Values::new(vec![1, 2, 3, 4, 5, 2]);
+    let excluder = Values::new(vec![2, 5]);
+    let mut iter = filter::ExcludeFrom::new(primary, excluder);
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+
+    // The excluder is drained again after a reset
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 4]);
+}
+
+#[test]
+fn include() {
+    let mut iter = filter::Include::with_whitelist(Values::new(vec![1, 2, 3, 4, 5]), vec![2, 4]);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![2, 4]);
+
+    iter.reset();
+    iter.include(5);
+    iter.include(5);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 4, 5]);
+}
+
+#[test]
+fn include_with_empty_whitelist() {
+    let iter = filter::Include::new(Values::new(vec![1, 2, 3]));
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn exclude_if() {
+    let mut iter = filter::ExcludeIf::new(Values::new(vec![1, 2, 3, 4, 5, 6]), |x: &i32| x % 2 == 0);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 5]);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 5]);
+}
+
+#[test]
+fn exclude_hashed_matches_exclude() {
+    let values = (0..200).map(|x| x * 7 % 31).collect::<Vec<i32>>();
+    let blacklist = vec![3, 8, 8, 0, 30, 17];
+
+    let linear = filter::Exclude::with_blacklist(values.iter().cloned(), blacklist.clone());
+    let mut hashed = filter::ExcludeHashed::with_blacklist(Values::new(values.clone()), blacklist);
+    let expected = linear.collect::<Vec<i32>>();
+    assert_eq!(hashed.by_ref().collect::<Vec<i32>>(), expected);
+
+    hashed.reset();
+    hashed.exclude(1);
+    assert_eq!(hashed.collect::<Vec<i32>>(), expected.into_iter().filter(|x| *x != 1).collect::<Vec<i32>>());
+}
+
+#[test]
+fn exclude_hashed_with_large_blacklist() {
+    // A linear scan would take 10^10 comparisons here
+    let blacklist = (0..100_000).map(|x| x * 2).collect::<Vec<u64>>();
+    let iter = filter::ExcludeHashed::with_blacklist(0..100_000, blacklist);
+
+    let start = std::time::Instant::now();
+    assert_eq!(iter.count(), 50_000);
+    assert!(start.elapsed() < std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn exclude_sorted_matches_exclude() {
+    let values = (0..100).map(|x| x * 7 % 23).collect::<Vec<i32>>();
+    let blacklist = vec![9, 3, 9, 0, 22, 3];
+
+    let linear = filter::Exclude::with_blacklist(values.iter().cloned(), blacklist.clone());
+    let mut sorted = filter::ExcludeSorted::with_sorted_blacklist(Values::new(values.clone()), blacklist);
+    let expected = linear.collect::<Vec<i32>>();
+    assert_eq!(sorted.by_ref().collect::<Vec<i32>>(), expected);
+
+    sorted.reset();
+    sorted.exclude(4);
+    sorted.exclude(4);
+    assert_eq!(sorted.collect::<Vec<i32>>(), expected.into_iter().filter(|x| *x != 4).collect::<Vec<i32>>());
+}
+
+#[test]
+fn exclude_excluded_count() {
+    let mut iter = filter::Exclude::with_blacklist(Values::new(vec![1, 2, 3, 2, 4, 5, 2]), vec![2, 5]);
+    assert_eq!(iter.excluded_count(), 0);
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+    assert_eq!(iter.excluded_count(), 4);
+
+    iter.reset();
+    assert_eq!(iter.excluded_count(), 0);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.excluded_count(), 1);
+}
+
+#[test]
+fn exclude_blacklist_management() {
+    let mut iter = filter::Exclude::new(Values::new(vec![1, 2, 3, 4]));
+    iter.exclude(2);
+    iter.force_exclude(3);
+    iter.force_exclude(3);
+    assert!(iter.is_excluded(&2));
+    assert!(iter.is_excluded(&3));
+    assert!(!iter.is_excluded(&4));
+
+    assert!(iter.remove(&3));
+    assert!(!iter.remove(&3));
+    assert!(!iter.is_excluded(&3));
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+
+    iter.reset();
+    iter.clear_blacklist();
+    assert!(!iter.is_excluded(&2));
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn exclude_with_blacklist_iter() {
+    let blacklist = [2, 4].iter().cloned().collect::<std::collections::HashSet<i32>>();
+    let iter = filter::Exclude::with_blacklist_iter(Values::new(vec![1, 2, 3, 4, 5]), blacklist);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 5]);
+
+    let iter = filter::Exclude::with_blacklist_iter(Values::new(vec![1, 2, 3, 4, 5]), (1..4).map(|x| x + 1));
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 5]);
+}
+
+#[test]
+fn unique() {
+    let mut iter = filter::Unique::new(Values::new(vec![1, 2, 2, 3, 1, 4]));
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+
+    // The objects already yielded are forgotten
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn unique_hashed() {
+    let mut iter = filter::UniqueHashed::new(Values::new(vec![1, 2, 2, 3, 1, 4]));
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn exclude_with_borrowed_values() {
+    let names = vec!["foo", "bar", "baz", "foo"].into_iter().map(String::from).collect::<Vec<String>>();
+    let mut iter = filter::Exclude::with_blacklist(Values::new(names), vec![String::from("bar")]);
+
+    iter.exclude_borrowed("foo");
+    iter.exclude_borrowed("foo");
+    assert!(iter.is_excluded("foo"));
+    assert!(iter.is_excluded(&String::from("bar")));
+    assert!(!iter.is_excluded("baz"));
+    assert_eq!(iter.excluded.len(), 2);
+    assert_eq!(iter.by_ref().collect::<Vec<String>>(), vec![String::from("baz")]);
+
+    assert!(iter.remove("bar"));
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<String>>(), vec![String::from("bar"), String::from("baz")]);
+}
+
+#[test]
+fn exclude_debug_prints_the_blacklist_length_and_the_iterator() {
+    let iter = filter::Exclude::with_blacklist(0..5, vec![1, 3]);
+    assert_eq!(format!("{:?}", iter), "Exclude { blacklist_len: 2, iterator: 0..5 }");
+}
+
+#[test]
+fn exclude_n_only_drops_the_first_occurrences() {
+    let mut iter = filter::ExcludeN::with_limits(Values::new(vec![3, 3, 3, 3]), vec![(3, 2)]);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![3, 3]);
+    assert_eq!(iter.remaining(&3), 0);
+
+    iter.reset();
+    assert_eq!(iter.remaining(&3), 2);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![3, 3]);
+
+    let mut iter = filter::ExcludeN::new(Values::new(vec![1, 2, 1, 2, 1]));
+    iter.exclude(1, 1);
+    iter.exclude(2, 1);
+    iter.exclude(1, 1);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 1]);
+}
+
+#[test]
+fn limit_yields_the_same_first_objects_after_a_reset() {
+    let exclude = filter::Exclude::with_blacklist(Values::new(vec![1, 2, 3, 4, 5, 6]), vec![2, 5]);
+    let mut iter = filter::Limit::new(exclude, 3);
+
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+    assert_eq!(iter.remaining(), 0);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+    assert_eq!(crate::child::ChildIterator::release_parent(iter).collect::<Vec<i32>>(), vec![6]);
+}
+
+#[test]
+fn take_while_reset_gates_an_exclude() {
+    let exclude = filter::Exclude::with_blacklist(Values::new(vec![1, 2, 3, 4, 5, 1]), vec![2]);
+    let mut iter = filter::TakeWhileReset::new(exclude, |x: &i32| *x < 5);
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+}
+
+#[test]
+fn skip_while_reset_gates_an_exclude() {
+    let exclude = filter::Exclude::with_blacklist(Values::new(vec![1, 2, 3, 4, 1, 5]), vec![4]);
+    let mut iter = filter::SkipWhileReset::new(exclude, |x: &i32| *x < 3);
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![3, 1, 5]);
+
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![3, 1, 5]);
+    assert_eq!(crate::child::ChildIterator::get_parent(&iter).excluded_count(), 1);
+}
+
+#[test]
+fn retain_allowed_set_can_change_between_resets() {
+    let mut iter = filter::Retain::from_set(Values::new(vec![1, 2, 3, 4, 2]), vec![2, 3, 2]);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![2, 3, 2]);
+
+    assert!(iter.disallow(&2));
+    assert!(!iter.disallow(&2));
+    iter.allow(4);
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![3, 4]);
+
+    assert!(!iter.is_allowed(&2));
+    iter.allow(1);
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 4]);
+}
+
+#[test]
+fn exclude_with_capacity_and_blacklist_accessors() {
+    let mut iter = filter::Exclude::with_capacity(0..6, 4);
+    assert_eq!(iter.blacklist_len(), 0);
+
+    iter.exclude(1);
+    iter.exclude(4);
+    iter.exclude(1);
+    assert_eq!(iter.blacklist_len(), 2);
+    assert_eq!(iter.iter_blacklist().collect::<Vec<&i32>>(), vec![&1, &4]);
+
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 2, 3, 5]);
+}
+
+#[test]
+fn exclude_blacklist_round_trip() {
+    let mut iter = filter::Exclude::new(vec![String::from("a"), String::from("b"), String::from("c")].into_iter());
+    iter.exclude(String::from("c"));
+    iter.exclude(String::from("a"));
+
+    let saved = iter.export_blacklist();
+    assert_eq!(saved, vec![String::from("c"), String::from("a")]);
+
+    let mut restored = filter::Exclude::with_blacklist(vec![String::from("a"), String::from("b"), String::from("c")].into_iter(), vec![String::from("a")]);
+    restored.import_blacklist(saved.clone());
+    assert_eq!(restored.blacklist_len(), 2);
+    assert_eq!(restored.iter_blacklist().collect::<Vec<&String>>(), vec!["a", "c"]);
+
+    assert_eq!(restored.export_blacklist().len(), saved.len());
+    assert_eq!(restored.collect::<Vec<String>>(), iter.collect::<Vec<String>>());
+}
+
+#[test]
+fn partition() {
+    let partition = filter::Partition::new(1..=6, |x: &i32| x % 2 == 0);
+
+    let mut even = partition.matching();
+    assert_eq!(even.by_ref().cloned().collect::<Vec<i32>>(), vec![2, 4, 6]);
+    even.reset();
+    assert_eq!(even.cloned().collect::<Vec<i32>>(), vec![2, 4, 6]);
+
+    let mut odd = partition.non_matching();
+    assert_eq!(odd.by_ref().cloned().collect::<Vec<i32>>(), vec![1, 3, 5]);
+    odd.reset();
+    assert_eq!(odd.len(), 3);
+
+    assert_eq!(partition.into_parts(), (vec![2, 4, 6], vec![1, 3, 5]));
+
+    let empty = filter::Partition::new(Vec::<i32>::new(), |_: &i32| true);
+    assert_eq!(empty.into_parts(), (vec![], vec![]));
+}
+
+#[test]
+fn exclude_by_key() {
+    use crate::child::ChildIterator;
+    use crate::reset::SliceResetIter;
+
+    // Not comparable on purpose
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    let users = [
+        User { id: 1, name: "alice" },
+        User { id: 2, name: "bob" },
+        User { id: 3, name: "carol" },
+        User { id: 2, name: "bob again" },
+    ];
+
+    let mut iter = filter::ExcludeByKey::new(SliceResetIter::new(&users), |user: &&User| user.id);
+    iter.exclude(2);
+    iter.exclude(2);
+    assert!(iter.is_excluded(&2));
+    assert!(!iter.is_excluded(&1));
+
+    assert_eq!(iter.by_ref().map(|user| user.name).collect::<Vec<&str>>(), vec!["alice", "carol"]);
+
+    iter.reset();
+    iter.exclude(3);
+    assert_eq!(iter.by_ref().map(|user| user.name).collect::<Vec<&str>>(), vec!["alice"]);
+    assert_eq!(iter.get_parent().len(), 0);
+}
+
+#[test]
+fn exclude_with() {
+    use crate::child::ChildIterator;
+    use crate::reset::SliceResetIter;
+
+    let words = ["foo", "bar", "FOO", "Foo", "baz"];
+    let mut iter = filter::ExcludeWith::new(SliceResetIter::new(&words), |a: &&&str, b: &&&str| a.eq_ignore_ascii_case(b));
+    iter.exclude(&"foo");
+    iter.exclude(&"FOO");
+    assert!(iter.is_excluded(&&"fOo"));
+    assert!(!iter.is_excluded(&&"bar"));
+
+    assert_eq!(iter.by_ref().copied().collect::<Vec<&str>>(), vec!["bar", "baz"]);
+
+    iter.reset();
+    iter.exclude(&"BAZ");
+    assert_eq!(iter.by_ref().copied().collect::<Vec<&str>>(), vec!["bar"]);
+    assert_eq!(iter.get_parent().len(), 0);
+}
+
+#[test]
+fn exclude_into_reversed() {
+    use crate::child::ChildIterator;
+
+    let array = [1, 2, 3, 4, 5, 2, 6];
+    let forward = filter::Exclude::with_blacklist(array.iter().cloned(), vec![2, 5]);
+    let mut expected = forward.clone().collect::<Vec<i32>>();
+    expected.reverse();
+
+    let mut reversed = forward.into_reversed();
+    assert!(reversed.is_excluded(&2));
+    assert_eq!(reversed.get_parent().len(), 7);
+    assert_eq!(reversed.by_ref().collect::<Vec<i32>>(), expected);
+    assert_eq!(reversed.excluded_count(), 3);
+
+    // Only the remaining objects are reversed
+    let mut forward = filter::Exclude::with_blacklist(array.iter().cloned(), vec![2]);
+    forward.next();
+    forward.track_distinct();
+    let mut reversed = forward.into_reversed();
+    assert_eq!(reversed.by_ref().collect::<Vec<i32>>(), vec![6, 5, 4, 3]);
+    assert_eq!(reversed.distinct_count(), 4);
+}
+
+#[test]
+fn scan_filter() {
+    use crate::reset::SliceResetIter;
+
+    let array = [4, 1, 3, 2, 6, 1];
+    let mut iter = filter::ScanFilter::new(SliceResetIter::new(&array), 0, |sum: &mut i32, x: &&i32| {
+        *sum += **x;
+        *sum > 5
+    });
+    assert_eq!(*iter.state(), 0);
+
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![3, 2, 6, 1]);
+    assert_eq!(*iter.state(), 17);
+
+    iter.reset();
+    assert_eq!(*iter.state(), 0);
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(*iter.state(), 8);
+}
+
+#[test]
+fn dedup_adjacent() {
+    use crate::reset::SliceResetIter;
+
+    let array = [1, 1, 2, 2, 2, 3, 1];
+    let mut iter = filter::DedupAdjacent::new(SliceResetIter::new(&array));
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 1]);
+
+    // The first object is yielded again after a reset, although it is equal to the last one
+    iter.reset();
+    assert_eq!(iter.cloned().collect::<Vec<i32>>(), vec![1, 2, 3, 1]);
+
+    let iter = filter::DedupAdjacent::new(Vec::<i32>::new().into_iter());
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn exclude_indices() {
+    use crate::reset::SliceResetIter;
+
+    let array = [10, 20, 30, 40];
+    let mut iter = filter::ExcludeIndices::new(SliceResetIter::new(&array), vec![0, 2]);
+
+    assert_eq!(iter.by_ref().cloned().collect::<Vec<i32>>(), vec![20, 40]);
+    assert_eq!(iter.position(), 4);
+
+    iter.reset();
+    assert_eq!(iter.position(), 0);
+    iter.exclude(3);
+    iter.exclude(3);
+    assert!(iter.is_excluded(3));
+    assert_eq!(iter.cloned().collect::<Vec<i32>>(), vec![20]);
+}
+
+#[test]
+fn exclude_excluded_slice() {
+    let mut iter = filter::Exclude::new(0..10);
+    assert!(iter.excluded().is_empty());
+
+    iter.exclude(7);
+    iter.exclude(2);
+    iter.exclude(7);
+    iter.force_exclude(2);
+    iter.exclude(5);
+    assert_eq!(iter.excluded(), &[7, 2, 2, 5]);
+
+    iter.remove(&2);
+    assert_eq!(iter.excluded(), &[7, 5]);
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![0, 1, 2, 3, 4, 6, 8, 9]);
+}
+
+#[test]
+fn reservoir() {
+    use crate::child::ChildIterator;
+    use crate::reset::SliceResetIter;
+
+    let source = (0..20).collect::<Vec<i32>>();
+    let mut iter = filter::Reservoir::new(SliceResetIter::new(&source), 4, 7);
+    assert_eq!(iter.seed(), 7);
+
+    // The sample only depends on the seed
+    assert_eq!(iter.by_ref().copied().collect::<Vec<i32>>(), vec![19, 10, 4, 7]);
+    assert_eq!(iter.get_parent().len(), 0);
+
+    iter.reset();
+    assert_eq!(iter.by_ref().copied().collect::<Vec<i32>>(), vec![19, 10, 4, 7]);
+    assert_eq!(filter::Reservoir::new(0..20, 4, 7).collect::<Vec<i32>>(), vec![19, 10, 4, 7]);
+
+    // Shorter than the reservoir: everything is kept in order
+    assert_eq!(filter::Reservoir::new(0..3, 4, 7).collect::<Vec<i32>>(), vec![0, 1, 2]);
+    assert_eq!(filter::Reservoir::new(0..3, 0, 7).next(), None);
+}
+
+#[test]
+fn enumerate_reset() {
+    use crate::child::ChildIterator;
+    use crate::reset::SliceResetIter;
+
+    let array = [1, 2, 3, 2, 4, 2, 5];
+    let mut iter = filter::EnumerateReset::new(filter::Exclude::with_blacklist(SliceResetIter::new(&array), vec![&2]));
+
+    // Numbered over the filtered output, not over the parent
+    let expected = vec![(0, &1), (1, &3), (2, &4), (3, &5)];
+    assert_eq!(iter.by_ref().collect::<Vec<(usize, &i32)>>(), expected);
+    assert_eq!(iter.passed(), 4);
+
+    iter.reset();
+    assert_eq!(iter.passed(), 0);
+    assert_eq!(iter.next(), Some((0, &1)));
+
+    iter.get_parent_mut().exclude(&3);
+    assert_eq!(iter.by_ref().collect::<Vec<(usize, &i32)>>(), vec![(1, &4), (2, &5)]);
+}
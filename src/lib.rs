@@ -1,10 +1,33 @@
 //! A crate containing general purpose iterators not included in the standard library but quite useful. Regrouped in this crate by usage
+//! 
+//! # Features
+//! - `std` (enabled by default): the types relying on the standard library, like the ones using a `HashSet` or threads. Implies `alloc`
+//! - `alloc`: the types relying on heap allocations, like the `filter` and `adapters` modules. Without it, the crate is `no_std` and only requires `core`
+//! - `derive`: the `#[derive(ResettableIterator)]` macro, forwarding `reset` to the field marked `#[resettable_parent]`
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
 pub mod filter;
 
+#[cfg(feature = "alloc")]
+pub mod adapters;
+
 pub mod share;
 
+pub mod combine;
+
+pub mod diagnostics;
+
 mod core;
 pub use crate::core::*;
 
+#[cfg(feature = "derive")]
+pub use iterators_collection_derive::ResettableIterator;
+
 pub mod child;
+
+pub mod reset;
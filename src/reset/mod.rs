@@ -0,0 +1,274 @@
+//! Some resettable iterators over the collections of the standard library, useful as the bottom of a resettable chain of iterators
+
+use core::ops::Range;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A resettable iterator over the members of a slice, yielding a reference to each of them. Unlike `std::slice::Iter`, it implements `ResettableIterator`
+/// 
+/// # Example
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// use iterators_collection::filter::Exclude;
+/// use iterators_collection::reset::SliceResetIter;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let array = [1, 2, 3, 4, 5];
+/// // `resettable_map` replaces `cloned` to keep the chain resettable
+/// let iter = SliceResetIter::new(&array).resettable_map(|x| *x);
+/// let mut iter = Exclude::with_blacklist(iter, vec![2, 4]);
+/// 
+/// assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 5]);
+/// iter.reset();
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 5]);
+/// # }
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Debug)]
+pub struct SliceResetIter<'a, T> {
+    slice: &'a [T],
+    cur: usize,
+}
+
+impl<'a, T> SliceResetIter<'a, T> {
+    /// Creates a `SliceResetIter` yielding the members of `slice` in order
+    pub fn new(slice: &'a [T]) -> Self {
+        Self {
+            slice,
+            cur: 0,
+        }
+    }
+
+    /// Returns the slice iterated over
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+}
+
+impl<'a, T> Iterator for SliceResetIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let returned = self.slice.get(self.cur)?;
+        self.cur += 1;
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len() - self.cur;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for SliceResetIter<'_, T> {}
+
+// `cur` stays equal to `slice.len()` once exhausted
+impl<T> core::iter::FusedIterator for SliceResetIter<'_, T> {}
+
+impl<T> crate::ResettableIterator for SliceResetIter<'_, T> {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+/// A resettable iterator over a range of indexes. Unlike `core::ops::Range`, it implements `ResettableIterator`, going back to the start of the range
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::reset::RangeResetIter;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let mut iter = RangeResetIter::new(2..5);
+/// assert_eq!(iter.by_ref().collect::<Vec<usize>>(), vec![2, 3, 4]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.next(), Some(2));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RangeResetIter {
+    range: Range<usize>,
+    cur: usize,
+}
+
+impl RangeResetIter {
+    /// Creates a `RangeResetIter` yielding the indexes of `range` in order
+    pub fn new(range: Range<usize>) -> Self {
+        Self {
+            cur: range.start,
+            range,
+        }
+    }
+
+    /// Returns the whole range iterated over
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+impl Iterator for RangeResetIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.cur >= self.range.end {
+            return None;
+        }
+
+        self.cur += 1;
+        Some(self.cur - 1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.range.end.saturating_sub(self.cur);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for RangeResetIter {}
+
+// `cur` stays greater or equal to `range.end` once exhausted
+impl core::iter::FusedIterator for RangeResetIter {}
+
+impl crate::ResettableIterator for RangeResetIter {
+    fn reset(&mut self) {
+        self.cur = self.range.start;
+    }
+}
+
+/// A resettable iterator owning a `Vec` and yielding a clone of each of its members, so they are still there for the next pass
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::reset::VecResetIter;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let mut iter = VecResetIter::new(vec![String::from("a"), String::from("b")]);
+/// assert_eq!(iter.by_ref().collect::<Vec<String>>(), vec!["a", "b"]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.next(), Some(String::from("a")));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct VecResetIter<T> {
+    vec: Vec<T>,
+    cur: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> VecResetIter<T> {
+    /// Creates a `VecResetIter` yielding clones of the members of `vec` in order
+    pub fn new(vec: Vec<T>) -> Self {
+        Self {
+            vec,
+            cur: 0,
+        }
+    }
+
+    /// Returns the members iterated over
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Destroys `self` and returns the `Vec` iterated over
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> Iterator for VecResetIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let returned = self.vec.get(self.cur)?.clone();
+        self.cur += 1;
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.vec.len() - self.cur;
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> ExactSizeIterator for VecResetIter<T> {}
+
+// `cur` stays equal to `vec.len()` once exhausted
+#[cfg(feature = "alloc")]
+impl<T: Clone> core::iter::FusedIterator for VecResetIter<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> crate::ResettableIterator for VecResetIter<T> {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+/// Conversion into a resettable iterator, in order to start a resettable chain of iterators from a common collection
+/// 
+/// # Example
+/// ```
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// use iterators_collection::filter::Exclude;
+/// use iterators_collection::reset::IntoResettable;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let mut iter = Exclude::with_blacklist(vec![1, 2, 3, 4].into_resettable(), vec![2]);
+/// assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3, 4]);
+/// 
+/// iter.reset();
+/// assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3, 4]);
+/// # }
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+/// 
+/// # Notes
+/// The iterators built from slices and `Vec`s yield clones of the members rather than references, so they can be filtered like the collections themselves. Use `SliceResetIter` to get references instead
+pub trait IntoResettable {
+    /// The type of the objects yielded
+    type Item;
+
+    /// The resettable iterator built
+    type IntoResettable: crate::ResettableIterator<Item = Self::Item>;
+
+    /// Creates a resettable iterator from `self`
+    fn into_resettable(self) -> Self::IntoResettable;
+}
+
+impl<'a, T: Clone> IntoResettable for &'a [T] {
+    type Item = T;
+    type IntoResettable = crate::ResettableMap<SliceResetIter<'a, T>, fn(&'a T) -> T>;
+
+    fn into_resettable(self) -> Self::IntoResettable {
+        crate::ResettableIterator::resettable_map(SliceResetIter::new(self), T::clone as fn(&'a T) -> T)
+    }
+}
+
+impl IntoResettable for Range<usize> {
+    type Item = usize;
+    type IntoResettable = RangeResetIter;
+
+    fn into_resettable(self) -> RangeResetIter {
+        RangeResetIter::new(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone> IntoResettable for Vec<T> {
+    type Item = T;
+    type IntoResettable = VecResetIter<T>;
+
+    fn into_resettable(self) -> VecResetIter<T> {
+        VecResetIter::new(self)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
@@ -0,0 +1,88 @@
+//! The unit-tests module for the reset module
+
+use crate::filter::{Exclude, Include};
+use crate::reset::{IntoResettable, RangeResetIter, SliceResetIter, VecResetIter};
+use crate::ResettableIterator;
+
+#[test]
+fn slice_reset_iter_resets() {
+    let array = [1, 2, 3];
+    let mut iter = SliceResetIter::new(&array);
+    assert_eq!(iter.len(), 3);
+
+    assert_eq!(iter.by_ref().collect::<Vec<&i32>>(), vec![&1, &2, &3]);
+    assert!(iter.next().is_none());
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.len(), 2);
+}
+
+#[test]
+fn slice_reset_iter_as_the_base_of_filters() {
+    let array = [1, 2, 3, 4, 5];
+    let mut iter = Exclude::with_blacklist(SliceResetIter::new(&array), vec![&2, &5]);
+    assert_eq!(iter.by_ref().collect::<Vec<&i32>>(), vec![&1, &3, &4]);
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<&i32>>(), vec![&1, &3, &4]);
+
+    let mut iter = Include::with_whitelist(SliceResetIter::new(&array).resettable_map(|x| *x), vec![2, 4]);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![2, 4]);
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![2, 4]);
+}
+
+#[test]
+fn into_resettable_slices() {
+    let array = [String::from("a"), String::from("b"), String::from("c")];
+    let mut iter = array[..].into_resettable();
+
+    let first_pass = iter.by_ref().collect::<Vec<String>>();
+    assert_eq!(first_pass, vec!["a", "b", "c"]);
+    assert!(iter.next().is_none());
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<String>>(), first_pass);
+
+    // Arrays are converted through their slice
+    let array = [1, 2, 3];
+    let mut iter = Exclude::with_blacklist(array.into_resettable(), vec![2]);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![1, 3]);
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![1, 3]);
+}
+
+#[test]
+fn into_resettable_ranges() {
+    let mut iter: RangeResetIter = (3..7).into_resettable();
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.range(), 3..7);
+
+    assert_eq!(iter.by_ref().collect::<Vec<usize>>(), vec![3, 4, 5, 6]);
+    assert!(iter.next().is_none());
+    assert_eq!(iter.len(), 0);
+
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<usize>>(), vec![3, 4, 5, 6]);
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let mut empty = (5..2).into_resettable();
+    assert_eq!(empty.len(), 0);
+    assert!(empty.next().is_none());
+}
+
+#[test]
+fn into_resettable_vecs() {
+    let mut iter: VecResetIter<i32> = vec![4, 5, 6].into_resettable();
+
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![4, 5, 6]);
+    iter.reset();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![4, 5, 6]);
+
+    let mut iter = Include::with_whitelist(iter, vec![5]);
+    iter.reset();
+    assert_eq!(iter.by_ref().collect::<Vec<i32>>(), vec![5]);
+    iter.reset();
+    assert_eq!(iter.collect::<Vec<i32>>(), vec![5]);
+}
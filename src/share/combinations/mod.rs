@@ -0,0 +1,198 @@
+//! Generalizations of the pairs of `DoubleIterator` to the subsets and to the tuples of any size
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// Iterates over all the subsets of `k` members of a slice, in the lexicographic order of their indexes
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::combinations::Combinations;
+/// 
+/// let array = [1, 2, 3, 4];
+/// let iter = Combinations::new(&array, 3);
+/// 
+/// let sums = iter.map(|subset| subset.into_iter().sum()).collect::<Vec<i32>>();
+/// assert_eq!(sums, vec![6, 7, 8, 9]);
+/// ```
+/// 
+/// The members of a subset are yielded in the order of the slice, and `indexes` gives their positions in the slice
+#[derive(Clone, Debug)]
+pub struct Combinations<'a, T> {
+    slice: &'a [T],
+    indexes: Vec<usize>,
+    done: bool,
+}
+
+impl<'a, T> Combinations<'a, T> {
+    /// Creates a `Combinations` yielding the subsets of `k` members of `slice`. A single empty subset is yielded if `k == 0`
+    /// 
+    /// # Panics
+    /// Panics if `k > slice.len()`
+    pub fn new(slice: &'a [T], k: usize) -> Self {
+        assert!(k <= slice.len());
+
+        Self {
+            slice,
+            indexes: (0..k).collect(),
+            done: false,
+        }
+    }
+
+    /// Returns the indexes of the members of the next subset, or `None` if all the subsets have been yielded
+    pub fn indexes(&self) -> Option<&[usize]> {
+        if self.done {
+            None
+        } else {
+            Some(&self.indexes)
+        }
+    }
+
+    /// Moves `indexes` to the next subset in lexicographic order, or returns Err if it was the last one
+    fn increment(&mut self) -> Result<(), ()> {
+        let len = self.slice.len();
+        let k = self.indexes.len();
+
+        // The last index which can still be moved forward
+        let position = (0..k).rev().find(|&i| self.indexes[i] < len - k + i).ok_or(())?;
+
+        self.indexes[position] += 1;
+        for i in (position + 1)..k {
+            self.indexes[i] = self.indexes[i - 1] + 1;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> crate::ResettableIterator for Combinations<'_, T> {
+    fn reset(&mut self) {
+        for (i, index) in self.indexes.iter_mut().enumerate() {
+            *index = i;
+        }
+
+        self.done = false;
+    }
+}
+
+impl<'a, T> Iterator for Combinations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let slice = self.slice;
+        let returned = self.indexes.iter().map(|&i| &slice[i]).collect();
+        self.done = self.increment().is_err();
+
+        Some(returned)
+    }
+}
+
+// Once exhausted, `done` stays `true` until a reset
+impl<T> FusedIterator for Combinations<'_, T> {}
+
+/// Iterates over all the sequences of `k` distinct indexes lower than `len`, in lexicographic order
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::combinations::Permutations;
+/// 
+/// let tuples = Permutations::new(3, 2).collect::<Vec<Vec<usize>>>();
+/// assert_eq!(tuples, vec![vec![0, 1], vec![0, 2], vec![1, 0], vec![1, 2], vec![2, 0], vec![2, 1]]);
+/// ```
+/// 
+/// For `k == 2`, the tuples are the pairs of `IndexPairs::new(len)` in the same order
+#[derive(Clone, Debug)]
+pub struct Permutations {
+    len: usize,
+    indexes: Vec<usize>,
+    used: Vec<bool>,
+    done: bool,
+}
+
+impl Permutations {
+    /// Creates a `Permutations` yielding the sequences of `k` distinct indexes lower than `len`. A single empty sequence is yielded if `k == 0`
+    /// 
+    /// # Panics
+    /// Panics if `k > len`
+    pub fn new(len: usize, k: usize) -> Self {
+        assert!(k <= len);
+
+        let mut iterator = Self {
+            len,
+            indexes: (0..k).collect(),
+            used: vec![false; len],
+            done: false,
+        };
+        crate::ResettableIterator::reset(&mut iterator);
+
+        iterator
+    }
+
+    /// Moves `indexes` to the next sequence in lexicographic order, or returns Err if it was the last one
+    fn increment(&mut self) -> Result<(), ()> {
+        let k = self.indexes.len();
+
+        for position in (0..k).rev() {
+            let current = self.indexes[position];
+            self.used[current] = false;
+
+            // The smallest free index greater than the current one
+            if let Some(next) = ((current + 1)..self.len).find(|&i| !self.used[i]) {
+                self.indexes[position] = next;
+                self.used[next] = true;
+
+                // The end of the sequence is filled with the smallest free indexes
+                for slot in (position + 1)..k {
+                    let index = (0..self.len).find(|&i| !self.used[i]).unwrap();
+                    self.indexes[slot] = index;
+                    self.used[index] = true;
+                }
+
+                return Ok(());
+            }
+        }
+
+        Err(())
+    }
+}
+
+impl crate::ResettableIterator for Permutations {
+    fn reset(&mut self) {
+        for flag in self.used.iter_mut() {
+            *flag = false;
+        }
+
+        for (i, index) in self.indexes.iter_mut().enumerate() {
+            *index = i;
+            self.used[i] = true;
+        }
+
+        self.done = false;
+    }
+}
+
+impl Iterator for Permutations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Vec<usize>> {
+        if self.done {
+            return None;
+        }
+
+        let returned = self.indexes.clone();
+        self.done = self.increment().is_err();
+
+        Some(returned)
+    }
+}
+
+// Once exhausted, `done` stays `true` until a reset
+impl FusedIterator for Permutations {}
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
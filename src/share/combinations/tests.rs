@@ -0,0 +1,70 @@
+//! The unit-tests module for the combinations module
+
+use super::{Combinations, Permutations};
+use crate::ResettableIterator;
+
+#[test]
+fn combinations_are_yielded_in_lexicographic_order() {
+    let array = [0, 1, 2, 3];
+    let mut iter = Combinations::new(&array, 2);
+
+    assert_eq!(iter.indexes(), Some(&[0, 1][..]));
+    let subsets = iter.by_ref().collect::<Vec<Vec<&i32>>>();
+    assert_eq!(subsets, vec![
+        vec![&0, &1], vec![&0, &2], vec![&0, &3],
+        vec![&1, &2], vec![&1, &3],
+        vec![&2, &3],
+    ]);
+    assert_eq!(iter.indexes(), None);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.count(), 6);
+}
+
+#[test]
+fn combinations_edge_sizes() {
+    let array = [1, 2, 3];
+
+    assert_eq!(Combinations::new(&array, 0).collect::<Vec<Vec<&i32>>>(), vec![Vec::<&i32>::new()]);
+    assert_eq!(Combinations::new(&array, 3).collect::<Vec<Vec<&i32>>>(), vec![vec![&1, &2, &3]]);
+}
+
+#[test]
+#[should_panic]
+fn combinations_panic_if_k_is_too_big() {
+    let array = [1, 2];
+    Combinations::new(&array, 3);
+}
+
+#[test]
+fn permutations_of_two_indexes_match_index_pairs() {
+    let mut iter = Permutations::new(3, 2);
+    let tuples = iter.by_ref().map(|tuple| (tuple[0], tuple[1])).collect::<Vec<(usize, usize)>>();
+
+    assert_eq!(tuples, crate::share::IndexPairs::new(3).collect::<Vec<(usize, usize)>>());
+    assert_eq!(tuples.len(), 6);
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(vec![0, 1]));
+    assert_eq!(Permutations::new(5, 2).count(), 20);
+}
+
+#[test]
+fn permutations_of_all_indexes() {
+    let tuples = Permutations::new(3, 3).collect::<Vec<Vec<usize>>>();
+    assert_eq!(tuples, vec![
+        vec![0, 1, 2], vec![0, 2, 1],
+        vec![1, 0, 2], vec![1, 2, 0],
+        vec![2, 0, 1], vec![2, 1, 0],
+    ]);
+
+    assert_eq!(Permutations::new(4, 3).count(), 24);
+    assert_eq!(Permutations::new(2, 0).collect::<Vec<Vec<usize>>>(), vec![Vec::<usize>::new()]);
+}
+
+#[test]
+#[should_panic]
+fn permutations_panic_if_k_is_too_big() {
+    Permutations::new(2, 3);
+}
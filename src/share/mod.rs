@@ -1,5 +1,21 @@
 //! A module about advanced memory sharing during iteration
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BinaryHeap;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::iter::FusedIterator;
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
 /// Iterates twice over the same collection
 /// 
 /// # Example
@@ -47,10 +63,58 @@
 ///     // Some code here
 /// });
 /// ```
+/// 
+/// The iterators of this module built from a `DoubleIterator`, like `MaskedPairs` or `ZOrderPairs`, yield raw pointers for the same reasons and have a `safe_for_each` method as well
+/// 
+/// # Several passes
+/// A `&mut DoubleIterator` is an iterator too, thanks to the implementation of `Iterator` for `&mut I` of the standard library, so a `for` loop can borrow the iterator rather than consuming it. It can then be reset and walked again
+/// ```
+/// use iterators_collection::share::DoubleIterator;
+/// use iterators_collection::ResettableIterator;
+/// 
+/// let mut array = [1, 2, 3];
+/// let mut iter = DoubleIterator::new(&mut array);
+/// 
+/// for _pass in 0..2 {
+///     for (i, j) in &mut iter {
+///         unsafe {
+///             *i += *j;
+///         }
+///     }
+/// 
+///     iter.reset();
+/// }
+/// ```
+/// 
+/// The pointers yielded by a borrowed iterator have the same caveats as the ones yielded by value: they must not be dereferenced at the same time as the pointers of another pair, and they must not be used anymore once the slice is accessed in any other way
+/// 
+/// # Soundness
+/// The following operations are sound, and are checked by the tests run under Miri:
+/// - `safe_for_each` and the lending iterators, which never dereference a raw pointer and split the slice between the two members instead
+/// - dereferencing both pointers of a pair at the same time, even mutably, since they are derived from the same pointer to the slice and point to distinct members
+/// - dereferencing the pointers of a pair until the next call to a method taking the iterator by mutable reference, like `next`
+/// 
+/// Using the pointers of a pair after such a call, or after accessing the slice in any other way, is undefined behavior even if the pair is not dereferenced at the same time as another one
 pub struct DoubleIterator<'a, T> {
     slice: &'a mut [T],
     first: usize,
     second: usize,
+
+    // The last pair not yielded by `next_back` yet
+    back_first: usize,
+    back_second: usize,
+
+    // Only the pairs with `first < second` are yielded if `true`
+    unordered: bool,
+
+    // The greatest distance between the indexes of the members of a pair
+    max_distance: usize,
+
+    // The indexes of the members of the pairs are multiples of `step`
+    step: usize,
+
+    // Set once the front cursor has gone past the last pair, so that `next` returns before touching the indexes
+    exhausted: bool,
 }
 
 impl<'a, T> DoubleIterator<'a, T> {
@@ -59,237 +123,4602 @@ impl<'a, T> DoubleIterator<'a, T> {
     /// # Panics
     /// Panics if `slice.len() < 2`
     pub fn new(slice: &'a mut [T]) -> Self {
-        assert!(slice.len() >= 2);
+        Self::try_new(slice).unwrap_or_else(|error| panic!("{}", error))
+    }
 
-        Self {
+    /// Creates a `DoubleIterator` from a slice, or returns an error if the slice has less than two members
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1];
+    /// let error = DoubleIterator::try_new(&mut array).err().unwrap();
+    /// assert_eq!(error.len, 1);
+    /// ```
+    pub fn try_new(slice: &'a mut [T]) -> Result<Self, TooShortError> {
+        let len = slice.len();
+        if len < 2 {
+            return Err(TooShortError { len });
+        }
+
+        Ok(Self {
             slice,
 
             first: 0,
             second: 1,
-        }
+
+            back_first: len - 1,
+            back_second: len - 2,
+
+            unordered: false,
+            max_distance: usize::MAX,
+            step: 1,
+
+            exhausted: false,
+        })
     }
 
-    /// Returns a mutable pointer to the `index`th element of the borrowed slice
+    /// Creates a `DoubleIterator` from a slice yielding each unordered pair once, as `(i, j)` with `i < j`
     /// 
-    /// # Unsafety
-    /// Indexes are not checked if the `debug_assert!`s are disabled
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
     /// 
-    /// This pointer is unsafe to use
-    unsafe fn nth_ptr(&mut self, index: usize) -> *mut T {
-        debug_assert!(index < self.slice.len());
-        self.slice.get_unchecked_mut(index) as *mut T
-    }
+    /// let mut array = [0, 1, 2];
+    /// let iter = DoubleIterator::new_combinations(&mut array);
+    /// 
+    /// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The mode is kept by all the methods of the iterator: `reset` brings it back to the pair `(0, 1)` and the traversal is still made of the combinations only, while `set` only accepts the pairs of the combinations
+    /// 
+    /// There are `n * (n - 1) / 2` pairs for a slice of length `n`
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    pub fn new_combinations(slice: &'a mut [T]) -> Self {
+        let mut iterator = Self::new(slice);
+        iterator.unordered = true;
+        crate::ResettableIterator::reset(&mut iterator);
 
-    /// Increments the indexes `first` and `second` or returns Err
-    fn increment(&mut self) -> Result<(), ()> {
-        loop {
-            // Increment
-            self.second += 1;
+        iterator
+    }
 
-            // Check for overflow
-            if self.second == self.slice.len() {
-                self.second = 0;
-                self.first += 1;
+    /// Creates a `DoubleIterator` from a slice yielding only the pairs `(i, j)` whose indexes are at most `d` apart, that is `|i - j| <= d`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 1, 2, 3];
+    /// let iter = DoubleIterator::with_max_distance(&mut array, 1);
+    /// 
+    /// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs, vec![(0, 1), (1, 0), (1, 2), (2, 1), (2, 3), (3, 2)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The pairs out of the window are skipped without being visited, so a traversal costs about `2 * d * n` steps for a slice of length `n` instead of `n * n`. Just like the combinations mode, the window is kept by `reset`, and `set` only accepts the pairs inside of it
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    /// 
+    /// Panics if `d == 0`
+    pub fn with_max_distance(slice: &'a mut [T], d: usize) -> Self {
+        assert_ne!(d, 0);
 
-                if self.first >= self.slice.len() {
-                    return Err(());
-                }
-            }
+        let mut iterator = Self::new(slice);
+        iterator.max_distance = d;
 
-            if self.first != self.second {
-                return Ok(());
-            }
-        }
+        iterator
     }
 
-    /// Runs the given closure in a safe context
+    /// Creates a `DoubleIterator` from a slice yielding only the pairs `(i, j)` where both `i` and `j` are multiples of `step`, to get an approximate traversal of a large slice
     /// 
     /// # Example
     /// ```
     /// use iterators_collection::share::DoubleIterator;
     /// 
-    /// let mut array = [1, 2, 3, 4, 5];
-    /// let iter = DoubleIterator::new(&mut array);
+    /// let mut array = [0, 1, 2, 3, 4, 5, 6];
+    /// let iter = DoubleIterator::with_stride(&mut array, 3);
     /// 
-    /// iter.safe_for_each(|i, j| {
-    ///     println!("Got i = {} and j = {}", i, j);
-    ///     assert_ne!(i, j);
-    /// });
+    /// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs, vec![(0, 3), (0, 6), (3, 0), (3, 6), (6, 0), (6, 3)]);
     /// ```
     /// 
     /// # Notes
-    /// Not like a legacy iteration using a `for` loop, i and j are references because it's safe to use in this context
-    pub fn safe_for_each<F: Fn(&mut T, &mut T)>(self, callback: F) {
-        for (i, j) in self {
-            unsafe {
-                callback(&mut *i, &mut *j);
-            }
+    /// If `step` doesn't divide `slice.len()`, the last members are visited as long as their indexes are multiples of `step`, so the traversal goes over the `slice.len().div_ceil(step)` members `0, step, 2 * step...` lower than `slice.len()`. A `step` of 1 yields the same pairs as `new`
+    /// 
+    /// Just like the other modes, the stride is kept by `reset`, and `set` only accepts the pairs of the strided traversal
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    /// 
+    /// Panics if `step == 0` or if `step >= slice.len()`, since no pair could be yielded
+    pub fn with_stride(slice: &'a mut [T], step: usize) -> Self {
+        assert_ne!(step, 0);
+        assert!(step < slice.len());
+
+        let mut iterator = Self::new(slice);
+        iterator.step = step;
+        crate::ResettableIterator::reset(&mut iterator);
+
+        iterator
+    }
+
+    /// Returns a builder combining several modes of the iterator, like the combinations mode with a stride. See `DoubleIteratorBuilder`
+    pub fn builder(slice: &'a mut [T]) -> DoubleIteratorBuilder<'a, T> {
+        DoubleIteratorBuilder {
+            slice,
+            combinations: false,
+            stride: 1,
+            max_distance: None,
+            start: None,
         }
     }
 
-    /// Sets the position of the iterator
+    /// Creates an iterator yielding the pairs of members of `slice` as if its indexes were permuted by `perm`: the pair `(i, j)` of the traversal yields `slice[perm[i]]` and `slice[perm[j]]`. The slice is not reordered
     /// 
-    /// # Parameters
-    /// `i` the position of the first pointer of the tuple returned by the `Iterator` trait's implementation
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
     /// 
-    /// `j` the position of the second one
+    /// let mut array = [10, 20, 30];
+    /// let iter = DoubleIterator::with_index_permutation(&mut array, vec![2, 0, 1]);
+    /// 
+    /// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs[..2], [(30, 10), (30, 20)]);
+    /// ```
     /// 
     /// # Panics
-    /// Panics if either `i` or `j` are out of range (greater or equal to `slice.len()`)
+    /// Panics if `slice.len() < 2`
     /// 
-    /// Panics if `i == j`
-    pub fn set(&mut self, i: usize, j: usize) {
-        assert_ne!(i, j);
-        assert!(i < self.slice.len() && j < self.slice.len());
+    /// Panics if `perm` is not a permutation of `0..slice.len()`
+    #[cfg(feature = "alloc")]
+    pub fn with_index_permutation(slice: &'a mut [T], perm: Vec<usize>) -> PermutedPairs<'a, T> {
+        assert_eq!(perm.len(), slice.len());
 
-        self.first = i;
-        self.second = j;
-    }
-}
+        let mut seen = vec![false; perm.len()];
+        for &index in perm.iter() {
+            assert!(index < perm.len() && !seen[index], "`perm` is not a permutation");
+            seen[index] = true;
+        }
 
-impl<T> crate::ResettableIterator for DoubleIterator<'_, T> {
-    fn reset(&mut self) {
-        self.first = 0;
-        self.second = 1;
+        PermutedPairs {
+            iterator: Self::new(slice),
+            perm,
+        }
     }
-}
 
-impl<T> Iterator for DoubleIterator<'_, T> {
-    type Item = (*mut T, *mut T);
+    /// Returns mutable pointers to the `first`th and the `second`th elements of the borrowed slice
+    /// 
+    /// # Unsafety
+    /// Indexes are not checked if the `debug_assert!`s are disabled
+    /// 
+    /// These pointers are unsafe to use
+    /// 
+    /// # Notes
+    /// Both pointers are derived from the same pointer to the whole slice. Taking a mutable reference to each element in turn instead would invalidate the first pointer under the stacked borrows model, since a new mutable borrow of the slice is created for the second one
+    unsafe fn pair_ptrs(&mut self, first: usize, second: usize) -> (*mut T, *mut T) {
+        debug_assert!(first < self.slice.len() && second < self.slice.len());
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.first == self.slice.len() {
-            return None;
-        }
+        let base = self.slice.as_mut_ptr();
+        (base.add(first), base.add(second))
+    }
 
-        let returned = Some(unsafe { (self.nth_ptr(self.first), self.nth_ptr(self.second)) });
-        std::mem::drop(self.increment()); // Dropping is a way to ignore the error which doesn't matter here
+    /// Checks, only if the `debug_assert!`s are enabled, that the front cursor points to a valid pair of distinct members of the slice. It is meant to be called whenever the cursor is about to be used or has just been moved, except in the exhausted state
+    fn debug_check_position(&self) {
+        let len = self.slice.len();
 
-        returned
+        debug_assert!(self.first < len, "DoubleIterator invariant violated: first index {} is out of a slice of length {}", self.first, len);
+        debug_assert!(self.second < len, "DoubleIterator invariant violated: second index {} is out of a slice of length {}", self.second, len);
+        debug_assert_ne!(self.first, self.second, "DoubleIterator invariant violated: both indexes are equal");
     }
-}
 
-/// A `DoubleIterator` iterating on one single "line" (see explanation below)
-/// 
-/// # Introduction
-/// The iteration cycle of a `DoubleIterator` can be seen as a matrix with `i` as the value of the line and `j` as the value of the collumn. For example, in an array as `[0, 1, 2, 3, 4]`, a `DoubleIterator` will return the numbered cells and discard the blanks
-/// ```text
-/// +---+---+---+---+---+---+
-/// |   |i=0|i=1|i=2|i=3|i=4|
-/// +---+---+---+---+---+---+
-/// |j=0|   | 1 | 2 | 3 | 4 |
-/// +---+---+---+---+---+---+
-/// |j=1| 5 |   | 6 | 7 | 8 |
-/// +---+---+---+---+---+---+
-/// |j=2| 9 |10 |   |11 |12 |
-/// +---+---+---+---+---+---+
-/// |j=3|13 |14 |15 |   |16 |
-/// +---+---+---+---+---+---+
-/// |j=4|17 |18 |19 |20 |   |
-/// +---+---+---+---+---+---+
-/// ```
-/// 
-/// In this example, the first iterator tuple returned (once the two members dereferenced) is `(0, 1)`, then `(0, 2)`, `(0, 3)`, `(0, 4)`, and at the end of line, `i` is reset and `j` is incrememented, returning `(1, 0)`, `(1, 2)` because there is a blank in the cell (`i=1`; `j=1`), `(1, 3)`...
-/// 
-/// The blanks are here because there can't be two mutable references on the same object.
-/// 
-/// But in some cases, you need to iterate only on one single line and not the whole grid, that's why `SingleLineIterator` exists.
-/// 
-/// # Hey, wait! Why using that iterator and not simply iterating manually?
-/// Just like `DoubleIterator` does, this iterator returns two raw pointers to a member of the slice to iterate
-/// 
-/// Since version 0.3.3, the prefered way to do this is to use the `safe_for_each` method
-pub struct SingleLineIterator<'a, T> {
-    slice: &'a mut [T],
-    index: usize,
-    cur: usize,
-}
+    /// Creates a `DoubleIterator` whose front cursor is at `(first, second)` without checking anything, in order to test the invariant checks
+    #[cfg(all(test, feature = "std"))]
+    fn with_raw_position(slice: &'a mut [T], first: usize, second: usize) -> Self {
+        let mut iterator = Self::new(slice);
+        iterator.first = first;
+        iterator.second = second;
 
-impl<'a, T> SingleLineIterator<'a, T> {
-    /// Returns a new `SingleLineIterator` which returns a tuple of a mutable reference to `slice[index]` and to another member of `slice` at each iteration
+        iterator
+    }
+
+    /// Returns `true` if all the pairs have been yielded, by either `next` or `next_back`. The pairs are yielded in lexicographic order, so it happens when the front cursor goes beyond the back one
     /// 
-    /// # Panics
-    /// Panics if `index` is greater or equal to `slice.len()`
-    pub fn new(slice: &'a mut [T], index: usize) -> Self {
-        assert!(index < slice.len());
+    /// # Notes
+    /// The `exhausted` flag is checked first, so once it is set the indexes are not even compared. It doesn't depend on the `debug_assert!`s: the cursor never points outside of the slice while the flag is cleared, and any `first` beyond the last line is considered exhausted anyway
+    fn is_exhausted(&self) -> bool {
+        self.exhausted || self.first >= self.slice.len() || (self.first, self.second) > (self.back_first, self.back_second)
+    }
 
-        Self {
-            slice,
-            index,
-            cur: if index == 0 {
-                1
-            } else {
-                0
-            },
+    /// Moves the front cursor to the exhausted state `(slice.len(), 0)` and sets the `exhausted` flag
+    fn exhaust(&mut self) {
+        self.first = self.slice.len();
+        self.second = 0;
+        self.exhausted = true;
+    }
+
+    /// Returns the first pair of the traversal
+    fn first_pair(&self) -> (usize, usize) {
+        let (low, _) = self.row_bounds(0);
+
+        // Skips the diagonal
+        (0, if low == 0 { self.step } else { low })
+    }
+
+    /// Returns the last pair of the traversal
+    fn last_pair(&self) -> (usize, usize) {
+        let last = self.slice.len() - 1;
+        self.previous_pair(last, self.row_bounds(last).1 + self.step).unwrap()
+    }
+
+    /// Returns `true` if the pairs of the traversal are not all the pairs of distinct members, which forbids the closed formulas of the positions of the pairs
+    fn is_sparse(&self) -> bool {
+        self.step != 1 || self.max_distance < self.slice.len() - 1
+    }
+
+    /// Returns the lowest and the greatest indexes the second member of a pair can have on the line of `first`, both being multiples of `step`. The line is empty if the former is greater than the latter, and `first` itself is skipped if it lies between them
+    fn row_bounds(&self, first: usize) -> (usize, usize) {
+        if !first.is_multiple_of(self.step) {
+            return (1, 0);
         }
+
+        let high = first.saturating_add(self.max_distance).min(self.slice.len() - 1);
+        let low = if self.unordered {
+            first + 1
+        } else {
+            first.saturating_sub(self.max_distance)
+        };
+
+        (low.div_ceil(self.step) * self.step, high / self.step * self.step)
     }
 
-    /// Runs the given closure in a safe context
-    /// 
-    /// # Example
-    /// ```
-    /// use iterators_collection::share::SingleLineIterator;
-    /// 
-    /// let mut array = [1, 2, 3, 4, 5];
-    /// let iter = SingleLineIterator::new(&mut array, 0);
-    /// 
-    /// iter.safe_for_each(|i, j| {
-    ///     println!("Got i = {} and j = {}", i, j);
-    ///     assert_ne!(i, j);
-    /// });
-    /// ```
-    /// 
-    /// # Notes
-    /// Not like a legacy iteration using a `for` loop, i and j are references because it's safe to use in this context
-    pub fn safe_for_each<F: Fn(&mut T, &mut T)>(self, callback: F) {
-        for (i, j) in self {
-            unsafe {
-                callback(&mut *i, &mut *j);
-            }
+    /// Returns the number of pairs on the line of `first`
+    fn row_size(&self, first: usize) -> usize {
+        let (low, high) = self.row_bounds(first);
+
+        if low > high {
+            0
+        } else if low <= first && first <= high {
+            (high - low) / self.step
+        } else {
+            (high - low) / self.step + 1
         }
     }
-}
 
-impl<T> crate::ResettableIterator for SingleLineIterator<'_, T> {
-    fn reset(&mut self) {
-        self.cur = 0;
+    /// Returns `true` if `(first, second)` is a pair of the full traversal
+    fn contains(&self, first: usize, second: usize) -> bool {
+        let (low, high) = self.row_bounds(first);
+        first < self.slice.len() && first != second && low <= second && second <= high && second.is_multiple_of(self.step)
     }
-}
 
-impl<'a, T> Iterator for SingleLineIterator<'a, T> {
-    type Item = (*mut T, *mut T);
+    /// Returns the number of pairs yielded by a full traversal
+    fn pair_total(&self) -> usize {
+        if self.is_sparse() {
+            (0..self.slice.len()).map(|first| self.row_size(first)).sum()
+        } else if self.unordered {
+            pair_count(self.slice.len()) / 2
+        } else {
+            pair_count(self.slice.len())
+        }
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        let returned = if self.cur >= self.slice.len() {
-            None
+    /// Returns the position in the traversal of the pair `(first, second)`
+    fn ordinal(&self, first: usize, second: usize) -> usize {
+        let len = self.slice.len();
+
+        if self.is_sparse() {
+            let (low, _) = self.row_bounds(first);
+            let column = (second - low) / self.step - (low <= first && first < second) as usize;
+
+            (0..first).map(|row| self.row_size(row)).sum::<usize>() + column
+        } else if self.unordered {
+            first * (2 * len - first - 1) / 2 + second - first - 1
         } else {
-            unsafe {
-                let ptr1 = self.slice.get_unchecked_mut(self.index) as *mut T;
-                let ptr2 = self.slice.get_unchecked_mut(self.cur)   as *mut T;
+            ordinal_of_pair(len, first, second)
+        }
+    }
 
-                Some((ptr1, ptr2))
-            }
-        };
+    /// Returns the pair at the position `ordinal` in the traversal
+    fn pair_at(&self, mut ordinal: usize) -> (usize, usize) {
+        if !self.unordered && !self.is_sparse() {
+            return pair_from_ordinal(self.slice.len(), ordinal);
+        }
 
-        self.cur += 1;
-        if self.cur == self.index {
-            self.cur += 1;
+        let mut first = 0;
+        while ordinal >= self.row_size(first) {
+            ordinal -= self.row_size(first);
+            first += 1;
         }
 
-        returned
+        // Skips the diagonal
+        let (low, _) = self.row_bounds(first);
+        let second = low + ordinal * self.step;
+        (first, if low <= first && second >= first { second + self.step } else { second })
     }
-}
 
-impl<'a, T> From<DoubleIterator<'a, T>> for SingleLineIterator<'a, T> {
-    fn from(src: DoubleIterator<'a, T>) -> Self {
-        Self {
-            cur: src.second,
-            index: src.first,
-            slice: src.slice,
+    /// Returns the pair yielded right before `(first, second)` in the traversal, or `None` if there is not any. `second` may be beyond the end of the line of `first`
+    fn previous_pair(&self, mut first: usize, mut second: usize) -> Option<(usize, usize)> {
+        loop {
+            let (low, _) = self.row_bounds(first);
+
+            // The previous member of the line, skipping the diagonal
+            let previous = match second.checked_sub(self.step) {
+                Some(index) if index == first => index.checked_sub(self.step),
+                other => other,
+            };
+
+            match previous {
+                Some(index) if index >= low => return Some((first, index)),
+
+                _ => {
+                    // Starts again from the end of the previous line
+                    first = first.checked_sub(1)?;
+                    second = self.row_bounds(first).1 + self.step;
+                },
+            }
         }
     }
-}
 
+    /// Decrements the indexes `back_first` and `back_second` or returns Err if the first pair is reached
+    fn decrement(&mut self) -> Result<(), ()> {
+        let (first, second) = self.previous_pair(self.back_first, self.back_second).ok_or(())?;
+        self.back_first = first;
+        self.back_second = second;
 
-#[cfg(test)]
-mod tests;
+        Ok(())
+    }
+
+    /// Returns the pair yielded right after `(first, second)` in the traversal, or `None` if there is not any
+    fn next_pair(&self, mut first: usize, mut second: usize) -> Option<(usize, usize)> {
+        let len = self.slice.len();
+
+        second += self.step;
+        if second == first {
+            second += self.step;
+        }
+
+        loop {
+            let (_, high) = self.row_bounds(first);
+            if second <= high {
+                return Some((first, second));
+            }
+
+            // Starts again from the beginning of the next line
+            first += 1;
+            if first >= len {
+                return None;
+            }
+
+            second = self.row_bounds(first).0;
+            if second == first {
+                second += self.step;
+            }
+        }
+    }
+
+    /// Increments the indexes `first` and `second` or returns Err
+    fn increment(&mut self) -> Result<(), ()> {
+        match self.next_pair(self.first, self.second) {
+            Some((first, second)) => {
+                self.first = first;
+                self.second = second;
+                self.debug_check_position();
+
+                Ok(())
+            },
+
+            None => {
+                self.exhaust();
+                Err(())
+            },
+        }
+    }
+
+    /// Moves to the next pair just like `next` does, but returns its indexes rather than the pointers to its members
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        let position = (self.first, self.second);
+        self.next()?;
+
+        Some(position)
+    }
+
+    /// Moves to the next pair and returns its indexes and mutable references to its members, made by splitting the slice between them so no raw pointer is dereferenced. This is what the `safe_*` methods are built on
+    fn next_split(&mut self) -> Option<(usize, usize, &mut T, &mut T)> {
+        let (first, second) = self.next_indices()?;
+
+        let (i, j) = split_pair(self.slice, first, second);
+
+        Some((first, second, i, j))
+    }
+
+    /// Turns `self` into an iterator over its remaining pairs `(i, j)` with `i < j` only, by switching to the combinations mode and moving both cursors inwards. The window and the stride are kept
+    fn into_upper_triangle(mut self) -> Self {
+        if self.is_exhausted() {
+            return self;
+        }
+
+        let (first, second) = (self.first, self.second);
+        let (back_first, back_second) = (self.back_first, self.back_second);
+        self.unordered = true;
+
+        // The line of the front cursor goes on from `second`, or from its first pair if `second < first`
+        let (low, high) = self.row_bounds(first);
+        let front = if second.max(low) <= high {
+            Some((first, second.max(low)))
+        } else {
+            self.next_pair(first, high)
+        };
+
+        let back = if back_second > back_first {
+            Some((back_first, back_second))
+        } else {
+            self.previous_pair(back_first, self.row_bounds(back_first).0)
+        };
+
+        match (front, back) {
+            (Some(front), Some(back)) if front <= back => {
+                self.first = front.0;
+                self.second = front.1;
+                self.back_first = back.0;
+                self.back_second = back.1;
+                self.debug_check_position();
+            },
+
+            _ => self.exhaust(),
+        }
+
+        self
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.safe_for_each(|i, j| {
+    ///     println!("Got i = {} and j = {}", i, j);
+    ///     assert_ne!(i, j);
+    /// });
+    /// ```
+    /// 
+    /// # Notes
+    /// Not like a legacy iteration using a `for` loop, i and j are references because it's safe to use in this context
+    /// 
+    /// The references are made by splitting the slice between the two members, so no raw pointer is dereferenced
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        let mut iterator = self.lending();
+
+        while let Some((i, j)) = iterator.next() {
+            callback(i, j);
+        }
+    }
+
+    /// Runs the given closure in a safe context, passing it `state` before the members of each pair. It is the same as capturing `state` in the closure, but the state stays usable by the caller while the closure is built elsewhere
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut interactions = Vec::new();
+    /// 
+    /// DoubleIterator::new_combinations(&mut array).safe_for_each_with(&mut interactions, |interactions, i, j| {
+    ///     interactions.push(*i * *j);
+    /// });
+    /// assert_eq!(interactions, vec![2, 3, 6]);
+    /// ```
+    pub fn safe_for_each_with<S, F: FnMut(&mut S, &mut T, &mut T)>(self, state: &mut S, mut callback: F) {
+        self.safe_for_each(|i, j| callback(state, i, j));
+    }
+
+    /// Runs the given closure in a safe context, passing it the number of pairs already visited by this call before the members of each pair. The counter starts at 0, whatever the current position of the iterator
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut ordinals = Vec::new();
+    /// 
+    /// DoubleIterator::new(&mut array).safe_for_each_enumerated(|ordinal, _i, _j| {
+    ///     ordinals.push(ordinal);
+    /// });
+    /// assert_eq!(ordinals, vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn safe_for_each_enumerated<F: FnMut(usize, &mut T, &mut T)>(self, mut callback: F) {
+        let mut ordinal = 0;
+
+        self.safe_for_each(|i, j| {
+            callback(ordinal, i, j);
+            ordinal += 1;
+        });
+    }
+
+    /// Builds an iterator over `slice` and runs the given closure on all its pairs in a safe context, returning the number of pairs processed. Unlike `new` followed by `safe_for_each`, it never panics: an error is returned if the slice is too short to contain a pair
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::{DoubleIterator, TooShortError};
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// assert_eq!(DoubleIterator::safe_for_each_checked(&mut array, |i, j| *i += *j), Ok(6));
+    /// 
+    /// let mut short = [1];
+    /// assert_eq!(DoubleIterator::safe_for_each_checked(&mut short, |_, _| ()), Err(TooShortError { len: 1 }));
+    /// ```
+    pub fn safe_for_each_checked<F: FnMut(&mut T, &mut T)>(slice: &'a mut [T], mut callback: F) -> Result<usize, TooShortError> {
+        let mut count = 0;
+
+        Self::try_new(slice)?.safe_for_each(|i, j| {
+            callback(i, j);
+            count += 1;
+        });
+
+        Ok(count)
+    }
+
+    /// Checks that `callback(a, b) == callback(b, a)` for every unordered pair of members of `slice`, which is a common expectation for pairwise interactions like forces. It is intended for tests
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let positions = [0.0, 1.5, 4.0];
+    /// DoubleIterator::assert_symmetric(&positions, |a: &f64, b: &f64| (a - b).abs());
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics with the indexes of the first pair found for which the results differ
+    /// 
+    /// # Notes
+    /// Just like `debug_assert!`, the check only runs if the debug assertions are enabled: in a release build, `callback` is never called and the `n * (n - 1) / 2` comparisons are not paid
+    pub fn assert_symmetric<R: PartialEq, F: FnMut(&T, &T) -> R>(slice: &[T], mut callback: F) {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+
+        for (i, a) in slice.iter().enumerate() {
+            for (j, b) in slice.iter().enumerate().skip(i + 1) {
+                if callback(a, b) != callback(b, a) {
+                    panic!("the function is not symmetric for the pair of indexes ({}, {})", i, j);
+                }
+            }
+        }
+    }
+
+    /// Runs the given closure in a safe context until it returns an error, which is then returned
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// let result = iter.safe_try_for_each(|i, j| {
+    ///     if *i + *j > 8 {
+    ///         Err((*i, *j))
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// 
+    /// assert_eq!(result, Err((4, 5)));
+    /// ```
+    /// 
+    /// # Notes
+    /// The closure is not called anymore once it failed. `Ok(())` is returned if it never failed
+    pub fn safe_try_for_each<E, F: FnMut(&mut T, &mut T) -> Result<(), E>>(mut self, mut callback: F) -> Result<(), E> {
+        while let Some((_, _, i, j)) = self.next_split() {
+            callback(i, j)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the given closure in a safe context, threading an accumulator through all the pairs, and returns the final value of the accumulator just like `Iterator::fold`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// let sum = iter.safe_fold(0, |sum, i, j| sum + *i * *j);
+    /// assert_eq!(sum, 22);
+    /// ```
+    pub fn safe_fold<A, F: FnMut(A, &mut T, &mut T) -> A>(mut self, init: A, mut callback: F) -> A {
+        let mut accumulator = init;
+        while let Some((_, _, i, j)) = self.next_split() {
+            accumulator = callback(accumulator, i, j);
+        }
+
+        accumulator
+    }
+
+    /// Runs the given closure in a safe context once per unordered pair, as `callback(slice[i], slice[j])` with `i < j`, leaving it to the closure to apply the effects on both members
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut forces = [0, 0, 0];
+    /// let iter = DoubleIterator::new(&mut forces);
+    /// 
+    /// // Each interaction is applied once, in both directions
+    /// iter.safe_for_each_symmetric(|a, b| {
+    ///     *a += 1;
+    ///     *b -= 1;
+    /// });
+    /// 
+    /// assert_eq!(forces, [2, 0, -2]);
+    /// ```
+    /// 
+    /// # Notes
+    /// Among the remaining pairs, `(j, i)` is skipped when `i < j`, which avoids counting the interactions of the full traversal twice. The pairs are the same in combinations mode
+    /// 
+    /// The skipped pairs are not visited at all: the remaining pairs with `i < j` are walked directly, just like in combinations mode
+    pub fn safe_for_each_symmetric<F: FnMut(&mut T, &mut T)>(self, callback: F) {
+        self.into_upper_triangle().safe_for_each(callback);
+    }
+
+    /// Runs the given closure in a safe context until it returns `ControlFlow::Break`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// use std::ops::ControlFlow;
+    /// 
+    /// let mut array: [f64; 4] = [0.0, 5.0, 2.0, 5.1];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// // Looks for the first colliding pair
+    /// let mut collision = None;
+    /// iter.safe_for_each_while(|i, j| {
+    ///     if (*i - *j).abs() < 0.5 {
+    ///         collision = Some((*i, *j));
+    ///         ControlFlow::Break(())
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// 
+    /// assert_eq!(collision, Some((5.0, 5.1)));
+    /// ```
+    /// 
+    /// # Notes
+    /// It behaves just like `safe_for_each` if the closure always returns `ControlFlow::Continue`
+    pub fn safe_for_each_while<F: FnMut(&mut T, &mut T) -> ControlFlow<()>>(mut self, mut callback: F) {
+        while let Some((_, _, i, j)) = self.next_split() {
+            if callback(i, j).is_break() {
+                return;
+            }
+        }
+    }
+
+    /// Runs the given closure in a safe context once for each batch of up to `W` partners of each first element of the pairs
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.safe_for_each_batched::<2, _>(|i, partners| {
+    ///     assert!(partners.len() <= 2);
+    ///     for j in partners.iter_mut() {
+    ///         **j += *i;
+    ///     }
+    /// });
+    /// ```
+    /// 
+    /// # Notes
+    /// The batches of a first element follow the traversal order, and the last batch of each one may be shorter than `W`
+    /// 
+    /// The references are built by splitting the slice around the first element, so the partners are distinct from each other and from the first element. That's why handing them out together is sound
+    /// 
+    /// # Panics
+    /// Panics if `W == 0`
+    #[cfg(feature = "alloc")]
+    pub fn safe_for_each_batched<const W: usize, F: FnMut(&mut T, &mut [&mut T])>(self, mut callback: F) {
+        assert_ne!(W, 0);
+
+        if self.is_exhausted() {
+            return;
+        }
+
+        let (mut first, mut second) = (self.first, self.second);
+        while first <= self.back_first {
+            // The partners already visited by `next` and `next_back` on the current line are skipped, `second` and `last` being the first and the last partners to visit
+            let last = if first < self.back_first { self.row_bounds(first).1 } else { self.back_second };
+            let step = self.step;
+
+            if second <= last {
+                let (left, rest) = self.slice.split_at_mut(first);
+                let (fixed, right) = rest.split_first_mut().unwrap();
+                let mut partners = left.iter_mut().enumerate()
+                    .chain(right.iter_mut().enumerate().map(|(index, item)| (first + 1 + index, item)))
+                    .filter(|(index, _)| second <= *index && *index <= last && index.is_multiple_of(step))
+                    .map(|(_, item)| item)
+                    .collect::<Vec<&mut T>>();
+
+                for batch in partners.chunks_mut(W) {
+                    callback(fixed, batch);
+                }
+            }
+
+            first += 1;
+            second = self.row_bounds(first).0;
+        }
+    }
+
+    /// Runs the given closure in parallel on every pair, using rounds of disjoint pairs
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [(1, 0), (2, 0), (3, 0), (4, 0)];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.par_safe_for_each_rounds(|i, j| i.1 += j.0);
+    /// assert_eq!(array, [(1, 9), (2, 8), (3, 7), (4, 6)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The unordered pairs are split into rounds by a round-robin tournament schedule, so no element appears twice in the same round. The pairs of a round are shared between some threads and the closure is called with both `(i, j)` and `(j, i)` by the thread processing the pair, or only with `(i, j)` where `i < j` in combinations mode. Since the pairs of a round are disjoint, no element is ever borrowed by two threads at the same time, which makes handing out mutable references across threads sound
+    /// 
+    /// Each round is a synchronization barrier: all the threads of a round are joined before the next round starts. That's why the order in which the pairs are processed is not the one of the traversal
+    /// 
+    /// `T` must be `Send` because its members are mutated from other threads, and the closure must be `Sync` because it is shared between them. All the pairs are processed, whatever the current position of `self` is
+    #[cfg(feature = "std")]
+    pub fn par_safe_for_each_rounds<F>(self, callback: F)
+    where
+        T: Send,
+        F: Fn(&mut T, &mut T) + Sync,
+    {
+        let (low, high) = (0, self.pair_total());
+        self.run_rounds(callback, low, high);
+    }
+
+    /// Runs the given closure in parallel on the same pairs as `safe_for_each`, using rounds of disjoint pairs
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [(1, 0), (2, 0), (3, 0), (4, 0)];
+    /// let iter = DoubleIterator::new(&mut array).from_pair(2, 0);
+    /// 
+    /// // Only the pairs from (2, 0) are processed
+    /// iter.par_safe_for_each(|i, j| i.1 += j.0);
+    /// assert_eq!(array, [(1, 0), (2, 0), (3, 7), (4, 6)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The index space is split in the same way as `par_safe_for_each_rounds` does, so the pairs processed at the same time never share a member and each thread gets real mutable references: the closure doesn't need to be commutative. Not like `par_safe_for_each_rounds`, only the pairs not yielded yet by `next` and `next_back` are processed, and the closure is called once for each of them with the pair in the order of the traversal
+    /// 
+    /// `T` must be `Send` because its members are mutated from other threads, and the closure must be `Sync` because it is shared between them. The order in which the pairs are processed is not the one of the traversal
+    #[cfg(feature = "std")]
+    pub fn par_safe_for_each<F>(self, callback: F)
+    where
+        T: Send,
+        F: Fn(&mut T, &mut T) + Sync,
+    {
+        if self.is_exhausted() {
+            return;
+        }
+
+        let low = self.ordinal(self.first, self.second);
+        let high = self.ordinal(self.back_first, self.back_second) + 1;
+        self.run_rounds(callback, low, high);
+    }
+
+    /// Runs the given closure in parallel on the pairs whose position in the traversal is in `low..high`, one round-robin round after the other
+    #[cfg(feature = "std")]
+    fn run_rounds<F>(self, callback: F, low: usize, high: usize)
+    where
+        T: Send,
+        F: Fn(&mut T, &mut T) + Sync,
+    {
+        let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let base = SharedSlice(self.slice.as_mut_ptr());
+        let selected = |first: usize, second: usize| {
+            self.contains(first, second) && (low..high).contains(&self.ordinal(first, second))
+        };
+
+        for round in round_robin(self.slice.len()) {
+            // Each disjoint pair of the round is turned into the ordered pairs to process
+            let tasks = round.into_iter().map(|(i, j)| {
+                let (a, b) = (i.min(j), i.max(j));
+                (a, b, selected(a, b), selected(b, a))
+            }).filter(|task| task.2 || task.3).collect::<Vec<(usize, usize, bool, bool)>>();
+
+            let chunk_size = tasks.len().div_ceil(workers).max(1);
+
+            // Joining the threads at the end of the scope is the barrier between two rounds
+            std::thread::scope(|scope| {
+                for chunk in tasks.chunks(chunk_size) {
+                    let base = &base;
+                    let callback = &callback;
+
+                    scope.spawn(move || {
+                        for &(i, j, forward, backward) in chunk {
+                            unsafe {
+                                let (a, b) = (&mut *base.0.add(i), &mut *base.0.add(j));
+                                if forward {
+                                    callback(a, b);
+                                }
+                                if backward {
+                                    callback(b, a);
+                                }
+                            }
+                        }
+                    });
+                }
+            });
+        }
+    }
+
+    /// Runs the given closure in a safe context on at most `budget` pairs and returns the number of pairs processed
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new(&mut array);
+    /// 
+    /// assert_eq!(iter.safe_for_each_budget(4, |i, j| *i += *j), 4);
+    /// // Resumes where the previous call stopped
+    /// assert_eq!(iter.safe_for_each_budget(4, |i, j| *i += *j), 2);
+    /// ```
+    /// 
+    /// # Notes
+    /// Not like `safe_for_each`, this method borrows `self`, so a later call resumes the iteration. A returned value lesser than `budget` means the iteration is over
+    pub fn safe_for_each_budget<F: FnMut(&mut T, &mut T)>(&mut self, budget: usize, mut callback: F) -> usize {
+        let mut count = 0;
+
+        while count < budget {
+            match self.next_split() {
+                Some((_, _, i, j)) => callback(i, j),
+                None               => break,
+            }
+
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Runs the given closure in a safe context and returns some statistics about the metric it returns for each pair
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array: [f64; 3] = [1.0, 2.0, 4.0];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// let stats = iter.safe_for_each_with_stats(|i, j| (*i - *j).abs());
+    /// assert_eq!(stats.count(), 6);
+    /// assert_eq!(stats.min(), Some(1.0));
+    /// assert_eq!(stats.max(), Some(3.0));
+    /// ```
+    pub fn safe_for_each_with_stats<F: FnMut(&mut T, &mut T) -> f64>(mut self, mut callback: F) -> PairStats {
+        let mut stats = PairStats::new();
+
+        while let Some((_, _, i, j)) = self.next_split() {
+            stats.push(callback(i, j));
+        }
+
+        stats
+    }
+
+    /// Runs one relaxation sweep: the given closure is called in a safe context once for each unordered pair and returns the magnitude of the change it made. The greatest change is returned, or 0 if there was no pair
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array: [f64; 3] = [0.0, 4.0, 8.0];
+    /// 
+    /// // Smooths the values until they converge
+    /// loop {
+    ///     let change = DoubleIterator::new(&mut array).relax_pairs(|i, j| {
+    ///         let delta = (*j - *i) / 4.0;
+    ///         *i += delta;
+    ///         *j -= delta;
+    ///         delta.abs()
+    ///     });
+    /// 
+    ///     if change < 1e-9 {
+    ///         break;
+    ///     }
+    /// }
+    /// 
+    /// assert!((array[0] - 4.0).abs() < 1e-6);
+    /// ```
+    /// 
+    /// # Notes
+    /// Only the remaining pairs `(i, j)` with `i < j` are processed, so each unordered pair is processed once per sweep. They are walked just like by `safe_for_each_symmetric`
+    pub fn relax_pairs<F: FnMut(&mut T, &mut T) -> f64>(self, mut callback: F) -> f64 {
+        let mut max_change: f64 = 0.0;
+
+        self.into_upper_triangle().safe_for_each(|i, j| {
+            max_change = max_change.max(callback(i, j));
+        });
+
+        max_change
+    }
+
+    /// Runs the given closure on each pair with two shared references, for the computations only reading the slice
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// let mut sum = 0;
+    /// iter.for_each_ref(|i, j| sum += i * j);
+    /// 
+    /// assert_eq!(sum, 2 * (2 + 3 + 6));
+    /// ```
+    /// 
+    /// # Notes
+    /// Just like the other ways to iterate, both `(i, j)` and `(j, i)` are visited
+    pub fn for_each_ref<F: FnMut(&T, &T)>(self, mut callback: F) {
+        for (i, j) in self {
+            unsafe {
+                callback(&*i, &*j);
+            }
+        }
+    }
+
+    /// Sets the position of the iterator
+    /// 
+    /// # Parameters
+    /// `i` the position of the first pointer of the tuple returned by the `Iterator` trait's implementation
+    /// 
+    /// `j` the position of the second one
+    /// 
+    /// # Panics
+    /// Panics if either `i` or `j` are out of range (greater or equal to `slice.len()`)
+    /// 
+    /// Panics if `i == j`
+    /// 
+    /// Panics if `i > j` and `self` was created by `new_combinations`
+    /// 
+    /// Panics if `i` and `j` are farther apart than the maximum distance given to `with_max_distance`
+    /// 
+    /// Panics if `i` or `j` is not a multiple of the step given to `with_stride`
+    /// 
+    /// # Notes
+    /// Only the front cursor is moved, the pairs already yielded by `next_back` stay excluded
+    pub fn set(&mut self, i: usize, j: usize) {
+        self.try_set(i, j).unwrap_or_else(|error| panic!("{}", error));
+    }
+
+    /// Sets the position of the iterator like `set`, but returns an error rather than panicking if `(i, j)` is not a pair of the traversal
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::{DoubleIterator, SetError};
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new(&mut array);
+    /// 
+    /// assert_eq!(iter.try_set(1, 1), Err(SetError::EqualIndexes(1)));
+    /// assert_eq!(iter.try_set(0, 3), Err(SetError::SecondOutOfRange { index: 3, len: 3 }));
+    /// assert_eq!(iter.try_set(2, 0), Ok(()));
+    /// ```
+    /// 
+    /// # Notes
+    /// The position is left unchanged if an error is returned
+    pub fn try_set(&mut self, i: usize, j: usize) -> Result<(), SetError> {
+        let len = self.slice.len();
+
+        if i >= len {
+            return Err(SetError::FirstOutOfRange { index: i, len });
+        }
+        if j >= len {
+            return Err(SetError::SecondOutOfRange { index: j, len });
+        }
+        if i == j {
+            return Err(SetError::EqualIndexes(i));
+        }
+        if !self.contains(i, j) {
+            return Err(SetError::NotInTraversal { first: i, second: j });
+        }
+
+        self.first = i;
+        self.second = j;
+        self.exhausted = false;
+        self.debug_check_position();
+
+        Ok(())
+    }
+
+    /// Returns the position of the iterator as `(i, j)`, the indexes of the members of the next pair to be yielded by `next`. It is `(slice.len(), 0)` once the iterator is exhausted by `next`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.next();
+    /// assert_eq!(iter.position(), (0, 2));
+    /// ```
+    pub fn position(&self) -> (usize, usize) {
+        (self.first, self.second)
+    }
+
+    /// Replaces the slice iterated over by `slice` and resets the iterator, so the next pair is the first one of the new slice. The mode of the iterator is kept
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut first = [1, 2];
+    /// let mut second = [3, 4, 5];
+    /// 
+    /// let mut iter = DoubleIterator::new_combinations(&mut first);
+    /// assert_eq!(iter.by_ref().count(), 1);
+    /// 
+    /// iter.rebind(&mut second);
+    /// assert_eq!(iter.count(), 3);
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    /// 
+    /// Panics if the stride given to `with_stride` is greater or equal to `slice.len()`
+    pub fn rebind(&mut self, slice: &'a mut [T]) {
+        assert!(slice.len() >= 2, "{}", TooShortError { len: slice.len() });
+        assert!(self.step < slice.len());
+
+        self.slice = slice;
+        crate::ResettableIterator::reset(self);
+    }
+
+    /// Returns the slice iterated over, whatever the position of the iterator
+    pub fn source(&self) -> &[T] {
+        self.slice
+    }
+
+    /// Returns the slice iterated over as mutable, whatever the position of the iterator. The position is left untouched, so the iteration goes on with the updated values
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.next();
+    /// iter.source_mut()[2] = 10;
+    /// assert_eq!(iter.source(), &[1, 2, 10]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The pointers yielded before must not be used while the returned reference is alive
+    pub fn source_mut(&mut self) -> &mut [T] {
+        self.slice
+    }
+
+    /// Returns the fraction of the pairs of the full traversal already yielded, by either `next` or `next_back`, as a value between `0.0` and `1.0`. It is `1.0` once the iterator is exhausted, which makes it handy to draw a progress bar
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new_combinations(&mut array);
+    /// assert_eq!(iter.progress(), 0.0);
+    /// 
+    /// iter.next();
+    /// assert!((iter.progress() - 1.0 / 3.0).abs() < 1e-9);
+    /// ```
+    pub fn progress(&self) -> f64 {
+        let total = self.pair_total();
+        (total - self.len()) as f64 / total as f64
+    }
+
+    /// Returns the next pair without advancing the iterator, or `None` if all the pairs have been yielded
+    /// 
+    /// # Notes
+    /// Just like the pairs yielded by `next`, the pointers returned are unsafe to use. `peek_indices` is prefered for a decision based on the position only
+    /// 
+    /// Nothing is buffered: the pair is computed from the front cursor, so a peek followed by `reset` or `set` never yields a stale pair
+    pub fn peek(&mut self) -> Option<(*mut T, *mut T)> {
+        if self.is_exhausted() {
+            return None;
+        }
+
+        Some(unsafe { self.pair_ptrs(self.first, self.second) })
+    }
+
+    /// Returns the indexes of the next pair without advancing the iterator, or `None` if all the pairs have been yielded
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new(&mut array);
+    /// 
+    /// assert_eq!(iter.peek_indices(), Some((0, 1)));
+    /// iter.next();
+    /// assert_eq!(iter.peek_indices(), Some((0, 2)));
+    /// ```
+    pub fn peek_indices(&self) -> Option<(usize, usize)> {
+        if self.is_exhausted() {
+            None
+        } else {
+            Some((self.first, self.second))
+        }
+    }
+
+    /// Runs the given closure on each pair and rolls the pair back to its previous state if the closure returns `false`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// // Moves one unit from `i` to `j` unless `i` would become negative
+    /// iter.try_mutate_pairs(|i, j| {
+    ///     *i -= 1;
+    ///     *j += 1;
+    ///     *i >= 0
+    /// });
+    /// ```
+    /// 
+    /// # Notes
+    /// `T` must implement `Clone` because both elements are snapshotted before calling the closure, which means a clone of each member of the pair is made at each iteration
+    pub fn try_mutate_pairs<F: FnMut(&mut T, &mut T) -> bool>(mut self, mut callback: F)
+    where
+        T: Clone,
+    {
+        while let Some((_, _, i, j)) = self.next_split() {
+            let (old_i, old_j) = (i.clone(), j.clone());
+
+            if !callback(i, j) {
+                *i = old_i;
+                *j = old_j;
+            }
+        }
+    }
+
+    /// Runs the given closure in a safe context, passing it a seed specific to the current pair as third argument
+    /// 
+    /// The seed is `pair_seed(first, second)` where `first` and `second` are the indexes of the two members of the pair, so the same pair always gets the same seed. See `pair_seed` for the hashing scheme
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1.0, 2.0, 3.0];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.safe_for_each_seeded(|i, _j, seed| {
+    ///     // A reproducible perturbation
+    ///     *i += (seed % 100) as f64 / 1000.0;
+    /// });
+    /// ```
+    pub fn safe_for_each_seeded<F: FnMut(&mut T, &mut T, u64)>(mut self, mut callback: F) {
+        while let Some((first, second, i, j)) = self.next_split() {
+            callback(i, j, pair_seed(first, second));
+        }
+    }
+
+    /// Computes `contribution` once for each unordered pair of members of `slice` and adds it to the accumulators of both members. The returned `Vec` contains the accumulator of each member of `slice`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let array = [1.0, 2.0, 3.0];
+    /// 
+    /// let sums = DoubleIterator::per_element_sum(&array, |i, j| i * j);
+    /// assert_eq!(sums, vec![2.0 + 3.0, 2.0 + 6.0, 3.0 + 6.0]);
+    /// ```
+    /// 
+    /// # Notes
+    /// All the pairs `(i, j)` with `i < j` are taken into account, so there is no iterator whose mode or position could be ignored. To only sum the remaining pairs of a `DoubleIterator`, use `accumulate_per_element`
+    #[cfg(feature = "alloc")]
+    pub fn per_element_sum<F: Fn(&T, &T) -> f64>(slice: &[T], contribution: F) -> Vec<f64> {
+        let mut sums = vec![0.0; slice.len()];
+
+        for i in 0..slice.len() {
+            for j in (i + 1)..slice.len() {
+                let value = contribution(&slice[i], &slice[j]);
+                sums[i] += value;
+                sums[j] += value;
+            }
+        }
+
+        sums
+    }
+
+    /// Calls `contribution` on each remaining pair `(slice[i], slice[j])` and adds the first value returned to the accumulator of `i` and the second one to the accumulator of `j`. The returned `Vec` contains the accumulator of each member of the borrowed slice, starting at `R::default()`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut positions = [0, 3, 10];
+    /// let iter = DoubleIterator::new_combinations(&mut positions);
+    /// 
+    /// // Each member is pulled towards the other ones
+    /// let forces = iter.accumulate_per_element(|a, b| (b - a, a - b));
+    /// assert_eq!(forces, vec![13, 4, -17]);
+    /// ```
+    /// 
+    /// # Notes
+    /// Unlike `per_element_sum`, which reads a whole slice, the mode and the position of `self` are respected and the contributions to both members can differ
+    #[cfg(feature = "alloc")]
+    pub fn accumulate_per_element<R, F>(self, mut contribution: F) -> Vec<R>
+    where
+        R: Default + Clone + core::ops::AddAssign,
+        F: FnMut(&T, &T) -> (R, R),
+    {
+        let mut results = vec![R::default(); self.slice.len()];
+
+        for ((i, j), (a, b)) in self.enumerate_pairs() {
+            let (to_i, to_j) = unsafe { contribution(&*a, &*b) };
+            results[i] += to_i;
+            results[j] += to_j;
+        }
+
+        results
+    }
+
+    /// Returns the symmetric adjacency matrix of `slice`, the cell `[i][j]` being `true` if and only if `connected` returns `true` for the pair
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let array: [i32; 3] = [1, 2, 10];
+    /// 
+    /// let matrix = DoubleIterator::adjacency_matrix(&array, |i, j| (i - j).abs() < 5);
+    /// assert_eq!(matrix, vec![
+    ///     vec![false, true, false],
+    ///     vec![true, false, false],
+    ///     vec![false, false, false],
+    /// ]);
+    /// ```
+    /// 
+    /// # Notes
+    /// `connected` is called once for each unordered pair `(i, j)` with `i < j` and its result is mirrored, so the matrix is symmetric by construction. The diagonal is always `false`
+    #[cfg(feature = "alloc")]
+    pub fn adjacency_matrix<F: Fn(&T, &T) -> bool>(slice: &[T], connected: F) -> Vec<Vec<bool>> {
+        let len = slice.len();
+        let mut matrix = vec![vec![false; len]; len];
+
+        for (i, item) in slice.iter().enumerate() {
+            for (j, other) in slice.iter().enumerate().skip(i + 1) {
+                let value = connected(item, other);
+                matrix[i][j] = value;
+                matrix[j][i] = value;
+            }
+        }
+
+        matrix
+    }
+
+    /// Returns the `k` unordered pairs of members of `slice` with the lowest score, or with the greatest one if `largest` is `true`, sorted from the best to the worst
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let positions: [f64; 4] = [0.0, 5.0, 0.5, 4.0];
+    /// 
+    /// // The two closest pairs
+    /// let closest = DoubleIterator::top_k_pairs(&positions, 2, |i, j| (i - j).abs(), false);
+    /// assert_eq!(closest, vec![((0, 2), 0.5), ((1, 3), 1.0)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// `score` is called once for each unordered pair `(i, j)` with `i < j` and the slice is only read. Only `k` pairs are kept in a binary heap during the pass, so the pairs are never all collected nor sorted
+    /// 
+    /// If there are less than `k` pairs, all of them are returned. The incomparable scores are considered equal
+    #[cfg(feature = "alloc")]
+    pub fn top_k_pairs<S, F>(slice: &[T], k: usize, score: F, largest: bool) -> Vec<((usize, usize), S)>
+    where
+        S: PartialOrd,
+        F: Fn(&T, &T) -> S,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut heap = BinaryHeap::with_capacity(k);
+
+        for (i, item) in slice.iter().enumerate() {
+            for (j, other) in slice.iter().enumerate().skip(i + 1) {
+                let entry = ScoredPair {
+                    score: score(item, other),
+                    pair: (i, j),
+                    largest,
+                };
+
+                // The top of the heap is the worst pair kept
+                if heap.len() < k {
+                    heap.push(entry);
+                } else if entry < *heap.peek().unwrap() {
+                    heap.pop();
+                    heap.push(entry);
+                }
+            }
+        }
+
+        heap.into_sorted_vec().into_iter().map(|entry| (entry.pair, entry.score)).collect()
+    }
+
+    /// Groups the members of `slice` by the key returned by `bucket_of` and returns the pairs of indexes of the members sharing the same key
+    /// 
+    /// This is a sparse alternative to the full pair traversal useful to find the candidate pairs of a collision detection
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let positions = [0.5, 3.2, 0.7, 3.9, 8.0];
+    /// 
+    /// // Buckets of width 1
+    /// let candidates = DoubleIterator::broad_phase(&positions, |x| *x as i32);
+    /// assert_eq!(candidates, vec![(0, 2), (1, 3)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// Only the pairs sharing the same bucket are returned, it is up to the caller to handle the neighbor buckets if needed, for example with coarser keys
+    /// 
+    /// Each unordered pair is returned once as `(i, j)` with `i < j`, and the pairs are sorted
+    #[cfg(feature = "std")]
+    pub fn broad_phase<K: Eq + Hash, F: Fn(&T) -> K>(slice: &[T], bucket_of: F) -> Vec<(usize, usize)> {
+        let mut buckets: HashMap<K, Vec<usize>> = HashMap::new();
+        for (index, item) in slice.iter().enumerate() {
+            buckets.entry(bucket_of(item)).or_default().push(index);
+        }
+
+        let mut pairs = Vec::new();
+        for bucket in buckets.values() {
+            for (n, i) in bucket.iter().enumerate() {
+                for j in bucket[(n + 1)..].iter() {
+                    pairs.push((*i, *j));
+                }
+            }
+        }
+
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// Returns an iterator yielding only the pairs having at least one dirty member, `dirty[i]` telling whether `slice[i]` is dirty
+    /// 
+    /// # Panics
+    /// Panics if `dirty.len()` is not the length of the borrowed slice
+    pub fn dirty_pairs<'b>(self, dirty: &'b [bool]) -> DirtyPairs<'a, 'b, T> {
+        assert_eq!(dirty.len(), self.slice.len());
+
+        DirtyPairs {
+            iterator: self,
+            dirty,
+        }
+    }
+
+    /// Returns an iterator yielding every `total`th remaining pair, starting with the one at offset `rank`, so the shards `0..total` cover all the remaining pairs exactly once
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 1, 2];
+    /// let shard = DoubleIterator::new(&mut array).shard(4, 1);
+    /// 
+    /// let pairs = shard.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs, vec![(0, 2), (2, 1)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// Each pair is skipped with `nth`, so a shard doesn't walk the pairs of the other ones. The shards are disjoint sets of pairs but different shards still share some members, so they must not mutate the slice at the same time
+    /// 
+    /// # Panics
+    /// Panics if `total == 0` or if `rank >= total`
+    pub fn shard(self, total: usize, rank: usize) -> ShardedPairs<'a, T> {
+        assert_ne!(total, 0);
+        assert!(rank < total);
+
+        ShardedPairs {
+            iterator: self,
+            total,
+            rank,
+            started: false,
+        }
+    }
+
+    /// Splits the remaining pairs into rows, one for each value of the first index, each row being a `SingleLineIterator` over the partners of that member
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut rows = DoubleIterator::new(&mut array).by_rows();
+    /// 
+    /// while let Some(row) = rows.next() {
+    ///     row.safe_for_each(|i, j| *j += *i);
+    /// }
+    /// assert_eq!(array, [11, 10, 7]);
+    /// ```
+    /// 
+    /// # Notes
+    /// Every row borrows the whole slice mutably, so a row must be dropped before asking for the next one. That's why `DoubleRows` is not an `Iterator` but has a `next` method of its own
+    /// 
+    /// The first row starts at the current position. The rows go on to the end of the grid, even if some pairs have already been yielded by `next_back`
+    /// 
+    /// # Panics
+    /// Panics if `self` has a stride or a maximum distance, since a `SingleLineIterator` can't skip the same pairs. The combinations mode is supported
+    pub fn by_rows(self) -> DoubleRows<'a, T> {
+        assert!(!self.is_sparse(), "`by_rows` doesn't support strides and maximum distances");
+
+        let exhausted = self.is_exhausted();
+        DoubleRows {
+            row: if exhausted { self.slice.len() } else { self.first },
+            start: Some(self.second),
+            unordered: self.unordered,
+            slice: self.slice,
+        }
+    }
+
+    /// Turns `self` into a `LendingDoubleIterator`, whose `next` method yields mutable references rather than raw pointers
+    pub fn lending(self) -> LendingDoubleIterator<'a, T> {
+        LendingDoubleIterator {
+            iterator: self,
+        }
+    }
+
+    /// Returns an iterator yielding the value returned by `callback` for each pair, without exposing the pointers
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let sum: i32 = DoubleIterator::new_combinations(&mut array).map_pairs(|a, b| a * b).sum();
+    /// 
+    /// assert_eq!(sum, 11);
+    /// ```
+    pub fn map_pairs<R, F>(self, mut callback: F) -> impl Iterator<Item = R> + 'a
+    where
+        F: FnMut(&T, &T) -> R + 'a,
+        R: 'a,
+    {
+        // The references only live during the call, while no other reference to the members exist
+        self.map(move |(i, j)| unsafe { callback(&*i, &*j) })
+    }
+
+    /// Returns an iterator calling `callback` on the members of each pair before yielding the pair unchanged, just like `Iterator::inspect`. It is handy to log the pairs visited
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = DoubleIterator::new_combinations(&mut array).inspect_pairs(|a, b| println!("Visiting ({}, {})", a, b));
+    /// 
+    /// for (i, j) in iter {
+    ///     unsafe {
+    ///         *j += *i;
+    ///     }
+    /// }
+    /// assert_eq!(array, [1, 3, 7]);
+    /// ```
+    /// 
+    /// # Notes
+    /// `callback` is called while the pair is yielded, so the pointers of the previous pairs must not be dereferenced anymore at this time, which is already required by `DoubleIterator`
+    pub fn inspect_pairs<F>(self, mut callback: F) -> impl Iterator<Item = (*mut T, *mut T)> + 'a
+    where
+        F: FnMut(&T, &T) + 'a,
+    {
+        self.inspect(move |&(i, j)| unsafe { callback(&*i, &*j) })
+    }
+
+    /// Returns an iterator yielding clones of the members of each pair, which can be used freely since they don't borrow the slice anymore
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let pairs = DoubleIterator::new_combinations(&mut array).cloned_pairs().collect::<Vec<(i32, i32)>>();
+    /// 
+    /// assert_eq!(pairs, vec![(1, 2), (1, 3), (2, 3)]);
+    /// ```
+    pub fn cloned_pairs(self) -> impl Iterator<Item = (T, T)> + 'a
+    where
+        T: Clone,
+    {
+        self.map_pairs(|a, b| (a.clone(), b.clone()))
+    }
+
+    /// Returns the sum of the values returned by `callback` for each pair. In combinations mode, each unordered pair is counted once
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut positions: [f64; 3] = [0.0, 1.0, 3.0];
+    /// let total = DoubleIterator::new_combinations(&mut positions).pairwise_sum(|a, b| (a - b).abs());
+    /// 
+    /// assert_eq!(total, 6.0);
+    /// ```
+    pub fn pairwise_sum<R, F>(self, mut callback: F) -> R
+    where
+        R: core::iter::Sum<R>,
+        F: FnMut(&T, &T) -> R,
+    {
+        self.map(|(i, j)| unsafe { callback(&*i, &*j) }).sum()
+    }
+
+    /// Returns a lending iterator yielding mutable references to the members of the pairs satisfying `predicate` only
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// // An exchange sort, swapping the members of each pair in the wrong order
+    /// let mut array = [3, 1, 4, 2];
+    /// let mut iter = DoubleIterator::new_combinations(&mut array).filter_pairs(|a, b| a > b);
+    /// 
+    /// while let Some((a, b)) = iter.next() {
+    ///     std::mem::swap(a, b);
+    /// }
+    /// 
+    /// assert_eq!(array, [1, 2, 3, 4]);
+    /// ```
+    pub fn filter_pairs<P: FnMut(&T, &T) -> bool>(self, predicate: P) -> FilteredPairs<'a, T, P> {
+        FilteredPairs {
+            iterator: self.lending(),
+            predicate,
+        }
+    }
+
+    /// Returns an iterator yielding the indexes of the members of each pair alongside the pointers, as `((i, j), (slice[i], slice[j]))`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [10, 20, 30];
+    /// let mut iter = DoubleIterator::new(&mut array).enumerate_pairs();
+    /// 
+    /// let ((i, j), (a, b)) = iter.next().unwrap();
+    /// assert_eq!((i, j), (0, 1));
+    /// assert_eq!(unsafe { (*a, *b) }, (10, 20));
+    /// ```
+    pub fn enumerate_pairs(self) -> EnumeratedPairs<'a, T> {
+        EnumeratedPairs {
+            iterator: self,
+        }
+    }
+
+    /// Returns an iterator yielding the indexes of the remaining pairs by batches of `batch` pairs, the last batch being shorter if the number of pairs is not a multiple of `batch`. Only indexes are yielded, so several pairs can be processed together without holding several pairs of pointers at the same time
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new_combinations(&mut array).batched(2);
+    /// 
+    /// assert_eq!(iter.next(), Some(vec![(0, 1), (0, 2)]));
+    /// assert_eq!(iter.next(), Some(vec![(1, 2)]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `batch == 0`
+    #[cfg(feature = "alloc")]
+    pub fn batched(self, batch: usize) -> BatchedPairs<'a, T> {
+        assert_ne!(batch, 0, "the batches must not be empty");
+
+        BatchedPairs {
+            iterator: self,
+            batch,
+        }
+    }
+
+    /// Returns the indexes of the members of each remaining pair, in the order they would be yielded. No pointer is dereferenced, so it is a safe way to know what a given configuration traverses
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = DoubleIterator::new_combinations(&mut array);
+    /// 
+    /// assert_eq!(iter.collect_index_pairs(), vec![(0, 1), (0, 2), (1, 2)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn collect_index_pairs(self) -> Vec<(usize, usize)> {
+        self.enumerate_pairs().map(|(indexes, _)| indexes).collect()
+    }
+
+    /// Returns a lending iterator yielding the members of each pair alongside `results[k]`, `k` being the position of the pair in the full traversal. It suits a matrix of results stored as a flat slice, one cell per pair
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut products = [0; 3];
+    /// 
+    /// DoubleIterator::new_combinations(&mut array)
+    ///     .zip_results(&mut products)
+    ///     .safe_for_each(|i, j, product| *product = *i * *j);
+    /// assert_eq!(products, [2, 3, 6]);
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `results` is too short to hold a cell for the last remaining pair
+    pub fn zip_results<'b, R>(self, results: &'b mut [R]) -> ZippedResults<'a, 'b, T, R> {
+        if !self.is_exhausted() {
+            assert!(results.len() > self.ordinal(self.back_first, self.back_second));
+        }
+
+        ZippedResults {
+            iterator: self.lending(),
+            results,
+        }
+    }
+
+    /// Returns the indexes of the first remaining pair whose members satisfy `predicate`, or `None` if there is none. The iteration stops as soon as a pair is found
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 4, 2, 6];
+    /// let iter = DoubleIterator::new_combinations(&mut array);
+    /// 
+    /// assert_eq!(iter.find_pair(|a, b| a + b == 8), Some((2, 3)));
+    /// ```
+    pub fn find_pair<P: FnMut(&T, &T) -> bool>(self, mut predicate: P) -> Option<(usize, usize)> {
+        self.enumerate_pairs()
+            .find(|(_, (a, b))| unsafe { predicate(&**a, &**b) })
+            .map(|(indexes, _)| indexes)
+    }
+
+    /// Returns an iterator over the indexes of the remaining pairs, from the current position to the end of the traversal. Neither the iterator nor the slice are touched, so it can be used to report the progress of an iteration
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = DoubleIterator::new_combinations(&mut array);
+    /// iter.next();
+    /// 
+    /// assert_eq!(iter.remaining_index_pairs().collect::<Vec<(usize, usize)>>(), vec![(0, 2), (1, 2)]);
+    /// assert_eq!(iter.position(), (0, 2));
+    /// ```
+    /// 
+    /// # Notes
+    /// A copy of the front cursor is moved just like `next` moves the original one, so walking all the remaining pairs takes the same time as a traversal
+    pub fn remaining_index_pairs(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let start = if self.is_exhausted() {
+            None
+        } else {
+            Some((self.first, self.second))
+        };
+        let back = (self.back_first, self.back_second);
+
+        // The pairs are yielded in lexicographic order, so the back cursor bounds them
+        core::iter::successors(start, move |&(first, second)| self.next_pair(first, second))
+            .take_while(move |&pair| pair <= back)
+    }
+
+    /// Returns the indexes of the remaining pair giving the greatest key, or `None` if there is no pair left. If several pairs give the greatest key, the last one is returned, just like `Iterator::max_by_key` does
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array: [i32; 4] = [3, 1, 7, 2];
+    /// let iter = DoubleIterator::new_combinations(&mut array);
+    /// 
+    /// // The farthest members
+    /// assert_eq!(iter.max_pair_by_key(|a, b| (a - b).abs()), Some((1, 2)));
+    /// ```
+    pub fn max_pair_by_key<K: Ord, F: FnMut(&T, &T) -> K>(self, mut f: F) -> Option<(usize, usize)> {
+        self.enumerate_pairs()
+            .max_by_key(|(_, (a, b))| unsafe { f(&**a, &**b) })
+            .map(|(indexes, _)| indexes)
+    }
+
+    /// Returns the indexes of the remaining pair giving the smallest key, or `None` if there is no pair left. If several pairs give the smallest key, the first one is returned, just like `Iterator::min_by_key` does
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array: [i32; 4] = [3, 1, 7, 2];
+    /// let iter = DoubleIterator::new_combinations(&mut array);
+    /// 
+    /// // The closest members
+    /// assert_eq!(iter.min_pair_by_key(|a, b| (a - b).abs()), Some((0, 3)));
+    /// ```
+    pub fn min_pair_by_key<K: Ord, F: FnMut(&T, &T) -> K>(self, mut f: F) -> Option<(usize, usize)> {
+        self.enumerate_pairs()
+            .min_by_key(|(_, (a, b))| unsafe { f(&**a, &**b) })
+            .map(|(indexes, _)| indexes)
+    }
+
+    /// Runs the given closure in a safe context, passing it the indexes of the members of the pair before the members themselves
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 0, 0];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// iter.safe_for_each_indexed(|i_index, j_index, i, _j| {
+    ///     *i += i_index * j_index;
+    /// });
+    /// 
+    /// assert_eq!(array, [0, 2, 2]);
+    /// ```
+    pub fn safe_for_each_indexed<F: FnMut(usize, usize, &mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((i_index, j_index, i, j)) = self.next_split() {
+            callback(i_index, j_index, i, j);
+        }
+    }
+
+    /// Returns an iterator yielding only the pairs enabled by `mask`, the bit `k % 64` of `mask[k / 64]` enabling the `k`th pair of the full traversal
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 1, 2];
+    /// // Enables the pairs (0, 1) and (1, 2)
+    /// let iter = DoubleIterator::new(&mut array).with_pair_mask(vec![0b1001]);
+    /// 
+    /// iter.safe_for_each(|i, j| {
+    ///     assert_eq!(*i + 1, *j);
+    /// });
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `mask` has less bits than there are pairs in the full traversal
+    #[cfg(feature = "alloc")]
+    pub fn with_pair_mask(self, mask: Vec<u64>) -> MaskedPairs<'a, T> {
+        assert!(mask.len() * 64 >= self.pair_total());
+
+        MaskedPairs {
+            iterator: self,
+            mask,
+        }
+    }
+
+    /// Splits the whole traversal into `k` chunks of consecutive pairs, which can be processed independently, for example by a thread pool
+    /// 
+    /// Each chunk is returned as `(start_first, start_second, end_first, end_second)`: it begins with the pair `(start_first, start_second)` and stops right before the pair `(end_first, end_second)`. The end of the traversal is denoted by `(slice.len(), 0)`. The chunks have the same size, give or take one pair, and some of them may be empty if there are less than `k` pairs
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = DoubleIterator::new(&mut array);
+    /// 
+    /// assert_eq!(iter.task_chunks(2), vec![(0, 1, 1, 2), (1, 2, 3, 0)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The chunks cover the whole traversal, whatever the current position of `self` is
+    /// 
+    /// # Panics
+    /// Panics if `k == 0`
+    #[cfg(feature = "alloc")]
+    pub fn task_chunks(&self, k: usize) -> Vec<(usize, usize, usize, usize)> {
+        assert_ne!(k, 0);
+
+        let len = self.slice.len();
+        let total = self.pair_total();
+        let bound = |chunk: usize| {
+            let ordinal = (total / k) * chunk + chunk.min(total % k);
+
+            if ordinal == total {
+                (len, 0)
+            } else {
+                self.pair_at(ordinal)
+            }
+        };
+
+        (0..k).map(|chunk| {
+            let (start_first, start_second) = bound(chunk);
+            let (end_first, end_second) = bound(chunk + 1);
+
+            (start_first, start_second, end_first, end_second)
+        }).collect()
+    }
+
+    /// Moves `self` to the pair `(i, j)` and returns it, so iterating over it yields the end of the full traversal starting at `(i, j)`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 1, 2];
+    /// let iter = DoubleIterator::new(&mut array).from_pair(2, 0);
+    /// 
+    /// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs, vec![(2, 0), (2, 1)]);
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics in the same cases as `set`
+    pub fn from_pair(mut self, i: usize, j: usize) -> Self {
+        self.set(i, j);
+        self
+    }
+
+    /// Moves `self` to the pair `(i, j)` and returns a mutable reference to it, which is an iterator too. It is useful to iterate from a given pair without moving `self`
+    /// 
+    /// # Panics
+    /// Panics in the same cases as `set`
+    pub fn iter_from(&mut self, i: usize, j: usize) -> &mut Self {
+        self.set(i, j);
+        self
+    }
+
+    /// Returns an iterator yielding each unordered pair once as `(slice[i], slice[j])` with `i < j`, in lexicographic order. It is double-ended, so the pairs can be consumed from both ends, for example in meet-in-the-middle algorithms
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 1, 2];
+    /// let mut iter = DoubleIterator::new(&mut array).ordered_pairs();
+    /// 
+    /// unsafe {
+    ///     let (i, j) = iter.next().unwrap();
+    ///     assert_eq!((*i, *j), (0, 1));
+    /// 
+    ///     let (i, j) = iter.next_back().unwrap();
+    ///     assert_eq!((*i, *j), (1, 2));
+    /// }
+    /// ```
+    /// 
+    /// # Notes
+    /// The whole pair set is yielded, whatever the current position of `self` is
+    pub fn ordered_pairs(self) -> OrderedPairs<'a, T> {
+        OrderedPairs::new(self.slice)
+    }
+
+    /// Returns an iterator yielding the remaining pairs of `self` but in Morton order (also called Z-order)
+    /// 
+    /// The pairs are sorted by the code obtained by interleaving the bits of `first` and `second`, which keeps successive pairs close in both indexes and improves cache behavior on large slices
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [0, 1, 2, 3];
+    /// let iter = DoubleIterator::new_combinations(&mut array).z_order();
+    /// 
+    /// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    /// assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The mode and the position of `self` are kept: the pairs yielded are exactly the ones `self` would still yield, in another order. Just like on `self`, `reset` brings back the whole traversal of the mode
+    pub fn z_order(self) -> ZOrderPairs<'a, T> {
+        ZOrderPairs::new(self)
+    }
+}
+
+impl<T> crate::ResettableIterator for DoubleIterator<'_, T> {
+    /// Brings both cursors back to the ends of the traversal. The combinations mode, the window and the stride are kept
+    fn reset(&mut self) {
+        let (first, second) = self.first_pair();
+        self.first = first;
+        self.second = second;
+        self.exhausted = false;
+
+        let (back_first, back_second) = self.last_pair();
+        self.back_first = back_first;
+        self.back_second = back_second;
+    }
+}
+
+impl<T> crate::ResettableToIterator for DoubleIterator<'_, T> {
+    type Position = (usize, usize);
+
+    /// Resets both cursors and moves the front one to the pair `(i, j)`
+    /// 
+    /// # Panics
+    /// Panics in the same cases as `set`
+    fn reset_to(&mut self, (i, j): (usize, usize)) {
+        crate::ResettableIterator::reset(self);
+        self.set(i, j);
+    }
+}
+
+impl<T> Iterator for DoubleIterator<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Checks the `exhausted` flag before anything else, so no pointer is computed from the indexes once the traversal is over, even without the `debug_assert!`s
+        if self.is_exhausted() {
+            return None;
+        }
+        self.debug_check_position();
+
+        let returned = Some(unsafe { self.pair_ptrs(self.first, self.second) });
+        let _ = self.increment(); // Sets the `exhausted` flag on error
+
+        returned
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.is_exhausted() {
+            0
+        } else {
+            self.ordinal(self.back_first, self.back_second) + 1 - self.ordinal(self.first, self.second)
+        };
+
+        (remaining, Some(remaining))
+    }
+
+    /// Jumps directly to the `n`-th next pair rather than calling `next` `n + 1` times. It takes a constant time for the iterators created by `new`, and a time proportional to the length of the slice for the other modes
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len() {
+            self.exhaust();
+            return None;
+        }
+
+        let (first, second) = self.pair_at(self.ordinal(self.first, self.second) + n);
+        self.first = first;
+        self.second = second;
+
+        self.next()
+    }
+
+    /// Returns the number of remaining pairs without walking them. It is `n * (n - 1)` for a new iterator over a slice of length `n`, or `n * (n - 1) / 2` in combinations mode
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> ExactSizeIterator for DoubleIterator<'_, T> {}
+
+// Once exhausted, `first` stays equal to `slice.len()`
+impl<T> FusedIterator for DoubleIterator<'_, T> {}
+
+impl<T> DoubleEndedIterator for DoubleIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.is_exhausted() {
+            return None;
+        }
+
+        let returned = Some(unsafe { self.pair_ptrs(self.back_first, self.back_second) });
+        if self.decrement().is_err() {
+            // The first pair has just been yielded
+            self.exhaust();
+        }
+
+        returned
+    }
+}
+
+// The pointers yielded are not printed, only the cursor and the length of the slice, so `T` doesn't need to implement `Debug`
+impl<T> fmt::Debug for DoubleIterator<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DoubleIterator")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .field("len", &self.slice.len())
+            .finish()
+    }
+}
+
+/// A pointer to the first member of a slice shared between threads working on disjoint members
+#[cfg(feature = "std")]
+struct SharedSlice<T>(*mut T);
+
+#[cfg(feature = "std")]
+unsafe impl<T: Send> Sync for SharedSlice<T> {}
+
+/// Splits the unordered pairs of indexes of a slice of length `len` into rounds of disjoint pairs, using the circle method of round-robin tournaments
+#[cfg(feature = "std")]
+fn round_robin(len: usize) -> Vec<Vec<(usize, usize)>> {
+    // A dummy member is added to get an even number of members, the pairs including it are dropped
+    let even = len + len % 2;
+    let mut rounds = Vec::with_capacity(even - 1);
+
+    for round in 0..(even - 1) {
+        let mut pairs = Vec::with_capacity(even / 2);
+        pairs.push((round, even - 1));
+
+        for k in 1..(even / 2) {
+            pairs.push(((round + k) % (even - 1), (round + even - 1 - k) % (even - 1)));
+        }
+
+        pairs.retain(|&(i, j)| i < len && j < len);
+        rounds.push(pairs);
+    }
+
+    rounds
+}
+
+/// A pair sorted by its score in a `BinaryHeap`, the worst pair being the greatest. See `DoubleIterator::top_k_pairs`
+#[cfg(feature = "alloc")]
+struct ScoredPair<S> {
+    score: S,
+    pair: (usize, usize),
+    largest: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<S: PartialOrd> Ord for ScoredPair<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self.score.partial_cmp(&other.score).unwrap_or(Ordering::Equal);
+
+        if self.largest {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: PartialOrd> PartialOrd for ScoredPair<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: PartialOrd> PartialEq for ScoredPair<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: PartialOrd> Eq for ScoredPair<S> {}
+
+/// Returns the number of pairs yielded by a full traversal of a `DoubleIterator` over a slice of length `len`
+fn pair_count(len: usize) -> usize {
+    len * (len - 1)
+}
+
+/// Returns the position in the traversal of a `DoubleIterator` over a slice of length `len` of the pair `(first, second)`
+fn ordinal_of_pair(len: usize, first: usize, second: usize) -> usize {
+    first * (len - 1) + if second < first { second } else { second - 1 }
+}
+
+/// Returns the pair at the position `ordinal` in the traversal of a `DoubleIterator` over a slice of length `len`
+fn pair_from_ordinal(len: usize, ordinal: usize) -> (usize, usize) {
+    let first = ordinal / (len - 1);
+    let column = ordinal % (len - 1);
+
+    // Skips the diagonal
+    (first, if column < first { column } else { column + 1 })
+}
+
+/// Returns mutable references to `slice[i]` and `slice[j]`, in this order. Splitting the slice between the two members proves they are distinct, so no unsafe code is needed
+/// 
+/// # Panics
+/// Panics if `i == j` or if one of them is out of range
+fn split_pair<T>(slice: &mut [T], i: usize, j: usize) -> (&mut T, &mut T) {
+    assert_ne!(i, j);
+
+    if i < j {
+        let (left, right) = slice.split_at_mut(j);
+        (&mut left[i], &mut right[0])
+    } else {
+        let (left, right) = slice.split_at_mut(i);
+        (&mut right[0], &mut left[j])
+    }
+}
+
+/// Concatenates several schedules of index pairs into one single schedule
+/// 
+/// Working with indexes rather than with `DoubleIterator`s avoids borrowing the same slice more than once, which makes it a tool to check that some shards of a traversal cover everything
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::merge_schedules;
+/// 
+/// let shard1 = vec![(0, 1), (1, 0)];
+/// let shard2 = vec![(0, 2)];
+/// 
+/// let merged = merge_schedules(vec![shard1, shard2]);
+/// assert_eq!(merged.collect::<Vec<(usize, usize)>>(), vec![(0, 1), (1, 0), (0, 2)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn merge_schedules<I>(schedules: Vec<I>) -> impl Iterator<Item = (usize, usize)>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    schedules.into_iter().flatten()
+}
+
+/// Runs `callback` in a safe context on every pair of members of each group, like `DoubleIterator::safe_for_each` would on every group one after the other. The groups with less than two members are skipped
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::grouped_double_for_each;
+/// 
+/// let mut groups = vec![vec![1], vec![2, 3], vec![4, 5, 6]];
+/// let mut count = 0;
+/// 
+/// grouped_double_for_each(&mut groups, |_, _| count += 1);
+/// assert_eq!(count, 2 + 6);
+/// ```
+/// 
+/// # Notes
+/// The pairs never mix two groups
+#[cfg(feature = "alloc")]
+pub fn grouped_double_for_each<T, F: FnMut(&mut T, &mut T)>(groups: &mut [Vec<T>], mut callback: F) {
+    for group in groups.iter_mut().filter(|group| group.len() >= 2) {
+        DoubleIterator::new(group).safe_for_each(&mut callback);
+    }
+}
+
+/// Splits `slice` into its first member, borrowed immutably, and a `DoubleIterator` over the other ones. It is useful when the first member is a fixed origin read by every pair of the rest
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::double_over_tail;
+/// 
+/// let mut array = [10, 1, 2, 3];
+/// let (origin, iter) = double_over_tail(&mut array);
+/// 
+/// iter.safe_for_each(|i, _j| *i += *origin);
+/// assert_eq!(array, [10, 21, 22, 23]);
+/// ```
+/// 
+/// # Notes
+/// The borrow of `slice` is split in two with `split_first_mut`: the origin is an immutable reference to `slice[0]` and the iterator borrows `slice[1..]` mutably. Both live as long as the borrow of `slice`, and since they don't overlap the origin can be read while the pairs are mutated
+/// 
+/// # Panics
+/// Panics if `slice.len() < 3`, since the iterator needs at least two members
+pub fn double_over_tail<T>(slice: &mut [T]) -> (&T, DoubleIterator<'_, T>) {
+    let (origin, rest) = slice.split_first_mut().expect("the slice is empty");
+
+    (origin, DoubleIterator::new(rest))
+}
+
+/// The SplitMix64 finalizer
+pub(crate) fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns the seed passed by `DoubleIterator::safe_for_each_seeded` for the pair of indexes `(first, second)`
+/// 
+/// The seed is `splitmix64(splitmix64(first) ^ second)` where `splitmix64` is the finalizer of the SplitMix64 generator, computed with wrapping arithmetic on `u64`:
+/// ```text
+/// z = x + 0x9E3779B97F4A7C15
+/// z = (z ^ (z >> 30)) * 0xBF58476D1CE4E5B9
+/// z = (z ^ (z >> 27)) * 0x94D049BB133111EB
+/// return z ^ (z >> 31)
+/// ```
+/// 
+/// This scheme doesn't depend on the platform nor on the version of the standard library, so the seeds are the same across runs. Note that `pair_seed(i, j) != pair_seed(j, i)` in general
+pub fn pair_seed(first: usize, second: usize) -> u64 {
+    splitmix64(splitmix64(first as u64) ^ second as u64)
+}
+
+/// A `DoubleIterator` yielding only the pairs enabled by a bitset. See `DoubleIterator::with_pair_mask`
+/// 
+/// The bit of a pair is the one of its ordinal in the full traversal, not of its rank among the pairs yielded, so a `reset` replays exactly the same selection
+#[cfg(feature = "alloc")]
+pub struct MaskedPairs<'a, T> {
+    iterator: DoubleIterator<'a, T>,
+    mask: Vec<u64>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> MaskedPairs<'_, T> {
+    /// Runs the given closure in a safe context
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((first, second)) = self.next_indices() {
+            let (i, j) = split_pair(self.iterator.slice, first, second);
+            callback(i, j);
+        }
+    }
+
+    /// Moves to the next enabled pair and returns its indexes
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        loop {
+            // `ordinal` is only meaningful for a pair of the traversal, not for the exhausted cursor
+            if self.iterator.is_exhausted() {
+                return None;
+            }
+
+            let ordinal = self.iterator.ordinal(self.iterator.first, self.iterator.second);
+            let pair = self.iterator.next_indices()?;
+
+            if self.mask[ordinal / 64] & (1 << (ordinal % 64)) != 0 {
+                return Some(pair);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::ResettableIterator for MaskedPairs<'_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Iterator for MaskedPairs<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, second) = self.next_indices()?;
+        Some(unsafe { self.iterator.pair_ptrs(first, second) })
+    }
+}
+
+/// Configures a `DoubleIterator` with several modes at once, which the dedicated constructors can't do. Created by `DoubleIterator::builder`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::DoubleIterator;
+/// 
+/// let mut array = [0, 1, 2, 3, 4];
+/// let iter = DoubleIterator::builder(&mut array)
+///     .combinations(true)
+///     .stride(2)
+///     .build()
+///     .unwrap();
+/// 
+/// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+/// assert_eq!(pairs, vec![(0, 2), (0, 4), (2, 4)]);
+/// ```
+/// 
+/// # Notes
+/// Nothing is checked before `build`, which returns a `BuildError` describing the first problem found
+pub struct DoubleIteratorBuilder<'a, T> {
+    slice: &'a mut [T],
+    combinations: bool,
+    stride: usize,
+    max_distance: Option<usize>,
+    start: Option<(usize, usize)>,
+}
+
+impl<'a, T> DoubleIteratorBuilder<'a, T> {
+    /// Yields each unordered pair once if `combinations` is `true`, like `DoubleIterator::new_combinations`
+    pub fn combinations(mut self, combinations: bool) -> Self {
+        self.combinations = combinations;
+        self
+    }
+
+    /// Yields only the pairs whose indexes are multiples of `step`, like `DoubleIterator::with_stride`
+    pub fn stride(mut self, step: usize) -> Self {
+        self.stride = step;
+        self
+    }
+
+    /// Yields only the pairs whose indexes are at most `d` apart, like `DoubleIterator::with_max_distance`
+    pub fn max_distance(mut self, d: usize) -> Self {
+        self.max_distance = Some(d);
+        self
+    }
+
+    /// Starts the iteration at the pair `(i, j)`, like `DoubleIterator::set`
+    pub fn start_at(mut self, i: usize, j: usize) -> Self {
+        self.start = Some((i, j));
+        self
+    }
+
+    /// Creates the configured `DoubleIterator`, or returns an error if the options are invalid or can't be used together
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::{BuildError, DoubleIterator};
+    /// 
+    /// let mut array = [0, 1, 2, 3, 4];
+    /// let error = DoubleIterator::builder(&mut array).stride(3).max_distance(2).build().err();
+    /// 
+    /// // No pair of multiples of 3 is close enough
+    /// assert_eq!(error, Some(BuildError::DistanceShorterThanStride { max_distance: 2, step: 3 }));
+    /// ```
+    pub fn build(self) -> Result<DoubleIterator<'a, T>, BuildError> {
+        let len = self.slice.len();
+        let step = self.stride;
+
+        if step == 0 {
+            return Err(BuildError::ZeroStride);
+        }
+        if len >= 2 && step >= len {
+            return Err(BuildError::StrideTooLarge { step, len });
+        }
+
+        let max_distance = match self.max_distance {
+            Some(0)             => return Err(BuildError::ZeroDistance),
+            Some(d) if d < step => return Err(BuildError::DistanceShorterThanStride { max_distance: d, step }),
+            Some(d)             => d,
+            None                => usize::MAX,
+        };
+
+        let mut iterator = DoubleIterator::try_new(self.slice).map_err(BuildError::TooShort)?;
+        iterator.unordered = self.combinations;
+        iterator.max_distance = max_distance;
+        iterator.step = step;
+        crate::ResettableIterator::reset(&mut iterator);
+
+        if let Some((i, j)) = self.start {
+            iterator.try_set(i, j).map_err(BuildError::BadStart)?;
+        }
+
+        Ok(iterator)
+    }
+}
+
+/// A `DoubleIterator` lending two mutable references to distinct members of the slice at each call of `next`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::LendingDoubleIterator;
+/// 
+/// let mut array = [1, 2, 3];
+/// let mut iter = LendingDoubleIterator::new(&mut array);
+/// 
+/// while let Some((i, j)) = iter.next() {
+///     *i += *j;
+/// }
+/// ```
+/// 
+/// # Notes
+/// This is not an `Iterator` because the references returned by `next` borrow the iterator, so only one pair can be held at a time. That's what makes them safe, and why this type can't be used with the adapters of the standard library
+pub struct LendingDoubleIterator<'a, T> {
+    iterator: DoubleIterator<'a, T>,
+}
+
+impl<'a, T> LendingDoubleIterator<'a, T> {
+    /// Creates a `LendingDoubleIterator` over the same pairs as `DoubleIterator::new`
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    pub fn new(slice: &'a mut [T]) -> Self {
+        DoubleIterator::new(slice).lending()
+    }
+
+    /// Returns mutable references to the members of the next pair, which live until the next call
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(&mut T, &mut T)> {
+        self.iterator.next_split().map(|(_, _, i, j)| (i, j))
+    }
+
+    /// Brings the iterator back to its first pair
+    pub fn reset(&mut self) {
+        crate::ResettableIterator::reset(&mut self.iterator);
+    }
+
+    /// Returns the number of pairs not lent yet
+    pub fn remaining(&self) -> usize {
+        self.iterator.len()
+    }
+}
+
+/// A lending iterator over the pairs of a `DoubleIterator` and their cell in a slice of results. See `DoubleIterator::zip_results`
+pub struct ZippedResults<'a, 'b, T, R> {
+    iterator: LendingDoubleIterator<'a, T>,
+    results: &'b mut [R],
+}
+
+impl<T, R> ZippedResults<'_, '_, T, R> {
+    /// Returns mutable references to the members of the next pair and to its result, which live until the next call
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(&mut T, &mut T, &mut R)> {
+        let (first, second) = self.iterator.iterator.peek_indices()?;
+        let ordinal = self.iterator.iterator.ordinal(first, second);
+
+        let (i, j) = self.iterator.next()?;
+        Some((i, j, &mut self.results[ordinal]))
+    }
+
+    /// Runs the given closure on each remaining pair and its result
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T, &mut R)>(mut self, mut callback: F) {
+        while let Some((i, j, result)) = self.next() {
+            callback(i, j, result);
+        }
+    }
+
+    /// Brings the iterator back to its first pair
+    pub fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+/// A lending iterator over the rows of a `DoubleIterator`, each of them being a `SingleLineIterator`. See `DoubleIterator::by_rows`
+pub struct DoubleRows<'a, T> {
+    slice: &'a mut [T],
+    row: usize,
+
+    // The partner the next row starts at, if it isn't the first of the row
+    start: Option<usize>,
+    unordered: bool,
+}
+
+impl<T> DoubleRows<'_, T> {
+    /// Returns the next non-empty row, which lives until the next call
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<SingleLineIterator<'_, T>> {
+        let len = self.slice.len();
+
+        while self.row < len {
+            let index = self.row;
+            self.row += 1;
+
+            let mut start = self.start.take().unwrap_or(if self.unordered { index + 1 } else { 0 });
+            if start == index {
+                start += 1;
+            }
+
+            if start < len {
+                return Some(SingleLineIterator::with_position(&mut *self.slice, index, start));
+            }
+        }
+
+        None
+    }
+}
+
+/// A `DoubleIterator` yielding every `total`th pair only. See `DoubleIterator::shard`
+pub struct ShardedPairs<'a, T> {
+    iterator: DoubleIterator<'a, T>,
+    total: usize,
+    rank: usize,
+    started: bool,
+}
+
+impl<T> ShardedPairs<'_, T> {
+    /// Runs the given closure in a safe context on each pair of the shard
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((first, second)) = self.next_indices() {
+            let (i, j) = split_pair(self.iterator.slice, first, second);
+            callback(i, j);
+        }
+    }
+
+    /// Moves to the next pair of the shard and returns its indexes
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        let skipped = if self.started {
+            self.total - 1
+        } else {
+            self.rank
+        };
+
+        self.started = true;
+        if skipped > 0 {
+            self.iterator.nth(skipped - 1)?;
+        }
+
+        self.iterator.next_indices()
+    }
+}
+
+impl<T> crate::ResettableIterator for ShardedPairs<'_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.started = false;
+    }
+}
+
+impl<T> Iterator for ShardedPairs<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, second) = self.next_indices()?;
+        Some(unsafe { self.iterator.pair_ptrs(first, second) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iterator.len();
+        let remaining = if self.started {
+            len / self.total
+        } else if len > self.rank {
+            (len - self.rank - 1) / self.total + 1
+        } else {
+            0
+        };
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for ShardedPairs<'_, T> {}
+
+impl<T> FusedIterator for ShardedPairs<'_, T> {}
+
+/// A lending iterator over the pairs of a `DoubleIterator` satisfying a predicate. See `DoubleIterator::filter_pairs`
+pub struct FilteredPairs<'a, T, P> {
+    iterator: LendingDoubleIterator<'a, T>,
+    predicate: P,
+}
+
+impl<T, P: FnMut(&T, &T) -> bool> FilteredPairs<'_, T, P> {
+    /// Returns mutable references to the members of the next pair satisfying the predicate, which live until the next call
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(&mut T, &mut T)> {
+        loop {
+            let (i, j) = self.iterator.iterator.peek_indices()?;
+            let slice = &*self.iterator.iterator.slice;
+
+            if (self.predicate)(&slice[i], &slice[j]) {
+                return self.iterator.next();
+            }
+
+            self.iterator.iterator.next();
+        }
+    }
+
+    /// Runs the given closure on each remaining pair satisfying the predicate
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((a, b)) = self.next() {
+            callback(a, b);
+        }
+    }
+
+    /// Brings the iterator back to its first pair
+    pub fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+/// A `DoubleIterator` yielding the indexes of the members of each pair alongside the pointers. See `DoubleIterator::enumerate_pairs`
+pub struct EnumeratedPairs<'a, T> {
+    iterator: DoubleIterator<'a, T>,
+}
+
+impl<T> crate::ResettableIterator for EnumeratedPairs<'_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+impl<T> Iterator for EnumeratedPairs<'_, T> {
+    type Item = ((usize, usize), (*mut T, *mut T));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.iterator.position();
+        let pair = self.iterator.next()?;
+
+        Some((position, pair))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for EnumeratedPairs<'_, T> {}
+
+/// A `DoubleIterator` yielding the indexes of its pairs by batches. See `DoubleIterator::batched`
+#[cfg(feature = "alloc")]
+pub struct BatchedPairs<'a, T> {
+    iterator: DoubleIterator<'a, T>,
+    batch: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::ResettableIterator for BatchedPairs<'_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Iterator for BatchedPairs<'_, T> {
+    type Item = Vec<(usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut batch = Vec::with_capacity(self.batch.min(self.iterator.len()));
+
+        while batch.len() < self.batch {
+            match self.iterator.peek_indices() {
+                Some(indexes) => batch.push(indexes),
+                None          => break,
+            }
+            self.iterator.next();
+        }
+
+        if batch.is_empty() {
+            None
+        } else {
+            Some(batch)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.iterator.len().div_ceil(self.batch);
+        (remaining, Some(remaining))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ExactSizeIterator for BatchedPairs<'_, T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> FusedIterator for BatchedPairs<'_, T> {}
+
+/// A `DoubleIterator` over permuted indexes. See `DoubleIterator::with_index_permutation`
+/// 
+/// The traversal is the one of the wrapped `DoubleIterator`, only the indexes of each pair are mapped through the permutation before the members are lent, so the pairs come in the order of the permuted indexes
+#[cfg(feature = "alloc")]
+pub struct PermutedPairs<'a, T> {
+    iterator: DoubleIterator<'a, T>,
+    perm: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PermutedPairs<'_, T> {
+    /// Runs the given closure in a safe context
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((first, second)) = self.next_indices() {
+            let (i, j) = split_pair(self.iterator.slice, first, second);
+            callback(i, j);
+        }
+    }
+
+    /// Moves to the next pair and returns the permuted indexes of its members
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        let (first, second) = self.iterator.next_indices()?;
+
+        Some((self.perm[first], self.perm[second]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::ResettableIterator for PermutedPairs<'_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Iterator for PermutedPairs<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, second) = self.next_indices()?;
+        Some(unsafe { self.iterator.pair_ptrs(first, second) })
+    }
+}
+
+/// An iterator over the unordered pairs of a slice. See `DoubleIterator::ordered_pairs`
+/// 
+/// The pairs can be taken from both ends of the lexicographic order: `next` and `next_back` meet in the middle without yielding a pair twice
+pub struct OrderedPairs<'a, T> {
+    slice: &'a mut [T],
+    front: (usize, usize),
+    back: (usize, usize),
+    remaining: usize,
+}
+
+impl<'a, T> OrderedPairs<'a, T> {
+    fn new(slice: &'a mut [T]) -> Self {
+        let len = slice.len();
+
+        Self {
+            slice,
+            front: (0, 1),
+            back: (len - 2, len - 1),
+            remaining: len * (len - 1) / 2,
+        }
+    }
+
+    /// Runs the given closure in a safe context
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((first, second)) = self.next_indices() {
+            let (i, j) = split_pair(self.slice, first, second);
+            callback(i, j);
+        }
+    }
+
+    /// Moves the front cursor to the next pair and returns the indexes of the current one
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let returned = self.front;
+        self.remaining -= 1;
+
+        self.front.1 += 1;
+        if self.front.1 == self.slice.len() {
+            self.front.0 += 1;
+            self.front.1 = self.front.0 + 1;
+        }
+
+        Some(returned)
+    }
+
+    /// Returns the pointers to the members of a pair
+    fn pointers(&mut self, (i, j): (usize, usize)) -> (*mut T, *mut T) {
+        let ptr = self.slice.as_mut_ptr();
+        unsafe { (ptr.add(i), ptr.add(j)) }
+    }
+}
+
+impl<T> crate::ResettableIterator for OrderedPairs<'_, T> {
+    fn reset(&mut self) {
+        let len = self.slice.len();
+
+        self.front = (0, 1);
+        self.back = (len - 2, len - 1);
+        self.remaining = len * (len - 1) / 2;
+    }
+}
+
+impl<T> Iterator for OrderedPairs<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pair = self.next_indices()?;
+        Some(self.pointers(pair))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for OrderedPairs<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let returned = self.pointers(self.back);
+        self.remaining -= 1;
+
+        // Checking `remaining` avoids an underflow once the first pair is reached
+        if self.remaining != 0 {
+            self.back.1 -= 1;
+            if self.back.1 == self.back.0 {
+                self.back.0 -= 1;
+                self.back.1 = self.slice.len() - 1;
+            }
+        }
+
+        Some(returned)
+    }
+}
+
+/// Some statistics about a metric computed for each pair. See `DoubleIterator::safe_for_each_with_stats`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PairStats {
+    count: usize,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl PairStats {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            min: None,
+            max: None,
+        }
+    }
+
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+
+    /// Returns the number of pairs processed
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the sum of the metric over all the pairs, 0 if there was no pair
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Returns the lowest metric or `None` if there was no pair
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// Returns the greatest metric or `None` if there was no pair
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// Returns the mean of the metric or `None` if there was no pair
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.sum / self.count as f64)
+        }
+    }
+}
+
+/// A `DoubleIterator` skipping the pairs whose two members are clean. See `DoubleIterator::dirty_pairs`
+/// 
+/// The clean pairs are still walked by the wrapped `DoubleIterator`, they are only filtered out, so a traversal costs the same whatever the number of dirty members is
+pub struct DirtyPairs<'a, 'b, T> {
+    iterator: DoubleIterator<'a, T>,
+    dirty: &'b [bool],
+}
+
+impl<T> DirtyPairs<'_, '_, T> {
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let dirty = [false, true, false, false, false];
+    /// let iter = DoubleIterator::new(&mut array).dirty_pairs(&dirty);
+    /// 
+    /// iter.safe_for_each(|i, j| {
+    ///     assert!(*i == 2 || *j == 2);
+    /// });
+    /// ```
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((first, second)) = self.next_indices() {
+            let (i, j) = split_pair(self.iterator.slice, first, second);
+            callback(i, j);
+        }
+    }
+
+    /// Moves to the next pair having a dirty member and returns its indexes
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        loop {
+            let (first, second) = self.iterator.next_indices()?;
+
+            if self.dirty[first] || self.dirty[second] {
+                return Some((first, second));
+            }
+        }
+    }
+}
+
+impl<T> crate::ResettableIterator for DirtyPairs<'_, '_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+impl<T> Iterator for DirtyPairs<'_, '_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, second) = self.next_indices()?;
+        Some(unsafe { self.iterator.pair_ptrs(first, second) })
+    }
+}
+
+/// Interleaves the bits of `first` (odd bits) and `second` (even bits)
+fn morton_encode(first: usize, second: usize) -> usize {
+    let mut code = 0;
+
+    for bit in 0..(usize::BITS as usize / 2) {
+        code |= ((second >> bit) & 1) << (2 * bit);
+        code |= ((first >> bit) & 1) << (2 * bit + 1);
+    }
+
+    code
+}
+
+/// Splits a Morton code back into `(first, second)`
+fn morton_decode(code: usize) -> (usize, usize) {
+    let mut first = 0;
+    let mut second = 0;
+
+    for bit in 0..(usize::BITS as usize / 2) {
+        second |= ((code >> (2 * bit)) & 1) << bit;
+        first |= ((code >> (2 * bit + 1)) & 1) << bit;
+    }
+
+    (first, second)
+}
+
+/// A `DoubleIterator` yielding its pairs in Morton order. See `DoubleIterator::z_order`
+/// 
+/// It walks the Morton codes in increasing order and only yields the ones decoding to a pair the wrapped `DoubleIterator` had left, so successive pairs stay close in both indexes
+pub struct ZOrderPairs<'a, T> {
+    // Its cursors bound the pairs to yield, they are not moved
+    iterator: DoubleIterator<'a, T>,
+    code: usize,
+    end: usize,
+    remaining: usize,
+}
+
+impl<'a, T> ZOrderPairs<'a, T> {
+    fn new(iterator: DoubleIterator<'a, T>) -> Self {
+        let side = iterator.slice.len().next_power_of_two();
+
+        Self {
+            end: morton_encode(side - 1, side - 1) + 1,
+            code: 0,
+            remaining: iterator.len(),
+            iterator,
+        }
+    }
+
+    /// Returns `true` if `(first, second)` is one of the pairs the `DoubleIterator` had left to yield
+    fn is_remaining(&self, first: usize, second: usize) -> bool {
+        let iterator = &self.iterator;
+
+        iterator.contains(first, second)
+            && (iterator.first, iterator.second) <= (first, second)
+            && (first, second) <= (iterator.back_first, iterator.back_second)
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::DoubleIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = DoubleIterator::new(&mut array).z_order();
+    /// 
+    /// iter.safe_for_each(|i, j| {
+    ///     println!("Got i = {} and j = {}", i, j);
+    ///     assert_ne!(i, j);
+    /// });
+    /// ```
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) {
+        while let Some((first, second)) = self.next_indices() {
+            let (i, j) = split_pair(self.iterator.slice, first, second);
+            callback(i, j);
+        }
+    }
+
+    /// Moves to the next remaining pair in Morton order and returns its indexes
+    fn next_indices(&mut self) -> Option<(usize, usize)> {
+        // Stops as soon as the last pair is found rather than decoding the remaining codes
+        while self.remaining > 0 && self.code < self.end {
+            let (first, second) = morton_decode(self.code);
+            self.code += 1;
+
+            // Skips the diagonal, the codes outside of the slice and the pairs out of the traversal
+            if self.is_remaining(first, second) {
+                self.remaining -= 1;
+                return Some((first, second));
+            }
+        }
+
+        None
+    }
+}
+
+impl<T> crate::ResettableIterator for ZOrderPairs<'_, T> {
+    /// Starts again from the first code, over the whole traversal of the mode of the `DoubleIterator`
+    fn reset(&mut self) {
+        self.iterator.reset();
+        self.code = 0;
+        self.remaining = self.iterator.len();
+    }
+}
+
+impl<T> Iterator for ZOrderPairs<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, second) = self.next_indices()?;
+        Some(unsafe { self.iterator.pair_ptrs(first, second) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for ZOrderPairs<'_, T> {}
+
+// `remaining` stays at 0 once exhausted
+impl<T> FusedIterator for ZOrderPairs<'_, T> {}
+
+/// A `DoubleIterator` iterating on one single "line" (see explanation below)
+/// 
+/// # Introduction
+/// The iteration cycle of a `DoubleIterator` can be seen as a matrix with `i` as the value of the line and `j` as the value of the collumn. For example, in an array as `[0, 1, 2, 3, 4]`, a `DoubleIterator` will return the numbered cells and discard the blanks
+/// ```text
+/// +---+---+---+---+---+---+
+/// |   |i=0|i=1|i=2|i=3|i=4|
+/// +---+---+---+---+---+---+
+/// |j=0|   | 1 | 2 | 3 | 4 |
+/// +---+---+---+---+---+---+
+/// |j=1| 5 |   | 6 | 7 | 8 |
+/// +---+---+---+---+---+---+
+/// |j=2| 9 |10 |   |11 |12 |
+/// +---+---+---+---+---+---+
+/// |j=3|13 |14 |15 |   |16 |
+/// +---+---+---+---+---+---+
+/// |j=4|17 |18 |19 |20 |   |
+/// +---+---+---+---+---+---+
+/// ```
+/// 
+/// In this example, the first iterator tuple returned (once the two members dereferenced) is `(0, 1)`, then `(0, 2)`, `(0, 3)`, `(0, 4)`, and at the end of line, `i` is reset and `j` is incrememented, returning `(1, 0)`, `(1, 2)` because there is a blank in the cell (`i=1`; `j=1`), `(1, 3)`...
+/// 
+/// The blanks are here because there can't be two mutable references on the same object.
+/// 
+/// But in some cases, you need to iterate only on one single line and not the whole grid, that's why `SingleLineIterator` exists.
+/// 
+/// # Hey, wait! Why using that iterator and not simply iterating manually?
+/// Just like `DoubleIterator` does, this iterator returns two raw pointers to a member of the slice to iterate
+/// 
+/// Since version 0.3.3, the prefered way to do this is to use the `safe_for_each` method
+pub struct SingleLineIterator<'a, T> {
+    slice: &'a mut [T],
+    index: usize,
+    cur: usize,
+    stride: usize,
+
+    // The partners from `end` have already been yielded by `next_back`
+    end: usize,
+}
+
+impl<'a, T> SingleLineIterator<'a, T> {
+    /// Returns a new `SingleLineIterator` which returns a tuple of a mutable reference to `slice[index]` and to another member of `slice` at each iteration
+    /// 
+    /// # Panics
+    /// Panics if `index` is greater or equal to `slice.len()`
+    pub fn new(slice: &'a mut [T], index: usize) -> Self {
+        Self::try_new(slice, index).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// Returns a new `SingleLineIterator` just like `new` does, or returns an error if `index` is greater or equal to `slice.len()`
+    pub fn try_new(slice: &'a mut [T], index: usize) -> Result<Self, BadIndexError> {
+        if index >= slice.len() {
+            return Err(BadIndexError {
+                index,
+                len: slice.len(),
+            });
+        }
+
+        Ok(Self::with_stride(slice, index, 1))
+    }
+
+    /// Returns a new `SingleLineIterator` which only yields every `stride`th partner of `slice[index]`, that is the members of `slice` whose index is a multiple of `stride`, `index` excepted
+    /// 
+    /// # Panics
+    /// Panics if `index` is greater or equal to `slice.len()`
+    /// 
+    /// Panics if `stride == 0`
+    pub fn with_stride(slice: &'a mut [T], index: usize, stride: usize) -> Self {
+        assert!(index < slice.len());
+        assert_ne!(stride, 0);
+
+        let end = slice.len();
+
+        Self {
+            slice,
+            index,
+            cur: if index == 0 {
+                stride
+            } else {
+                0
+            },
+            stride,
+            end,
+        }
+    }
+
+    /// Returns a new `SingleLineIterator` whose fixed element is `slice[index]` and whose next partner is `slice[cur]`, which is useful to resume a saved position
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4];
+    /// let iter = SingleLineIterator::with_position(&mut array, 1, 2);
+    /// 
+    /// assert_eq!(iter.position(), (1, 2));
+    /// assert_eq!(iter.count(), 2);
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `index` or `cur` is greater or equal to `slice.len()`
+    /// 
+    /// Panics if `cur == index`
+    pub fn with_position(slice: &'a mut [T], index: usize, cur: usize) -> Self {
+        let mut iterator = Self::new(slice, index);
+        iterator.set_cur(cur);
+
+        iterator
+    }
+
+    /// Returns the position of the iterator as `(index, cur)`, `index` being the index of the fixed element and `cur` the one of the next partner to be yielded. `cur` is beyond the end of the slice once the iterator is exhausted
+    pub fn position(&self) -> (usize, usize) {
+        (self.index, self.cur)
+    }
+
+    /// Returns the slice iterated over, whatever the position of the iterator
+    pub fn source(&self) -> &[T] {
+        self.slice
+    }
+
+    /// Returns the slice iterated over as mutable, whatever the position of the iterator. The position is left untouched, so the iteration goes on with the updated values
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = SingleLineIterator::new(&mut array, 0);
+    /// 
+    /// iter.next();
+    /// iter.source_mut()[2] = 10;
+    /// assert_eq!(iter.source(), &[1, 2, 10]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The pointers yielded before must not be used while the returned reference is alive
+    pub fn source_mut(&mut self) -> &mut [T] {
+        self.slice
+    }
+
+    /// Changes the fixed element to `slice[index]` and resets the iterator, so all its partners are yielded again
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let mut iter = SingleLineIterator::new(&mut array, 0);
+    /// 
+    /// for index in 0..3 {
+    ///     iter.set_index(index);
+    ///     assert_eq!(iter.by_ref().count(), 2);
+    /// }
+    /// ```
+    /// 
+    /// # Panics
+    /// Panics if `index` is greater or equal to `slice.len()`
+    pub fn set_index(&mut self, index: usize) {
+        assert!(index < self.slice.len());
+
+        self.index = index;
+        crate::ResettableIterator::reset(self);
+    }
+
+    /// Sets the position of the iterator, so the partner of the next pair is `slice[cur]`
+    /// 
+    /// # Panics
+    /// Panics if `cur` is out of range (greater or equal to `slice.len()`)
+    /// 
+    /// Panics if `cur == index`
+    pub fn set_cur(&mut self, cur: usize) {
+        assert_ne!(cur, self.index);
+        assert!(cur < self.slice.len());
+
+        self.cur = cur;
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = SingleLineIterator::new(&mut array, 0);
+    /// 
+    /// iter.safe_for_each(|i, j| {
+    ///     println!("Got i = {} and j = {}", i, j);
+    ///     assert_ne!(i, j);
+    /// });
+    /// ```
+    /// 
+    /// # Notes
+    /// Not like a legacy iteration using a `for` loop, i and j are references because it's safe to use in this context
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        for (i, j) in self {
+            unsafe {
+                callback(&mut *i, &mut *j);
+            }
+        }
+    }
+
+    /// Runs the given closure in a safe context until it returns an error, which is then returned
+    /// 
+    /// # Notes
+    /// The closure is not called anymore once it failed. `Ok(())` is returned if it never failed
+    pub fn safe_try_for_each<E, F: FnMut(&mut T, &mut T) -> Result<(), E>>(self, mut callback: F) -> Result<(), E> {
+        for (i, j) in self {
+            unsafe {
+                callback(&mut *i, &mut *j)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs the given closure in a safe context, threading an accumulator through all the pairs, and returns the final value of the accumulator just like `Iterator::fold`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3];
+    /// let iter = SingleLineIterator::new(&mut array, 0);
+    /// 
+    /// let sum = iter.safe_fold(0, |sum, i, j| sum + *i * *j);
+    /// assert_eq!(sum, 5);
+    /// ```
+    pub fn safe_fold<A, F: FnMut(A, &mut T, &mut T) -> A>(self, init: A, mut callback: F) -> A {
+        let mut accumulator = init;
+        for (i, j) in self {
+            unsafe {
+                accumulator = callback(accumulator, &mut *i, &mut *j);
+            }
+        }
+
+        accumulator
+    }
+
+    /// Runs the given closure in a safe context, the first argument always being the fixed element `slice[index]` and the second one its current partner. Both of them can be mutated
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [0, 1, 2, 3];
+    /// let iter = SingleLineIterator::new(&mut array, 0);
+    /// 
+    /// // Moves everything to the fixed element
+    /// iter.safe_for_each_both(|fixed, partner| {
+    ///     *fixed += *partner;
+    ///     *partner = 0;
+    /// });
+    /// 
+    /// assert_eq!(array, [6, 0, 0, 0]);
+    /// ```
+    /// 
+    /// # Notes
+    /// A new mutable reference to the fixed element is handed out at each call, but it is always the same element, so its mutations persist across the calls and accumulating into it works
+    pub fn safe_for_each_both<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        for (fixed, partner) in self {
+            unsafe {
+                callback(&mut *fixed, &mut *partner);
+            }
+        }
+    }
+
+    /// Updates the fixed element by folding in all its partners, which are only read
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = SingleLineIterator::new(&mut array, 0);
+    /// 
+    /// iter.reduce_into_fixed(|fixed, partner| *fixed += partner);
+    /// assert_eq!(array[0], 15);
+    /// ```
+    /// 
+    /// # Notes
+    /// The partners are passed in the order of the line traversal
+    pub fn reduce_into_fixed<F: FnMut(&mut T, &T)>(self, mut callback: F) {
+        for (i, j) in self {
+            unsafe {
+                callback(&mut *i, &*j);
+            }
+        }
+    }
+
+    /// Runs the given closure on the remaining partners of the fixed element, shared between several threads. The first argument is the fixed element, which can only be read, and the second one its partner, which can be mutated
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::SingleLineIterator;
+    /// 
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// let iter = SingleLineIterator::new(&mut array, 2);
+    /// 
+    /// iter.par_safe_for_each(|fixed, other| *other *= *fixed);
+    /// assert_eq!(array, [3, 6, 3, 12, 15]);
+    /// ```
+    /// 
+    /// # Notes
+    /// The fixed element is shared between all the threads, which is why `T` must implement `Sync`, while the partners are sent to the threads, which is why `T` must implement `Send`. Each partner is touched by exactly one call, so no synchronization is needed between the calls, but the order of the calls is not specified
+    #[cfg(feature = "std")]
+    pub fn par_safe_for_each<F>(self, callback: F)
+    where
+        T: Send + Sync,
+        F: Fn(&T, &mut T) + Sync,
+    {
+        let (index, cur, end, stride) = (self.index, self.cur, self.end, self.stride);
+        let (left, rest) = self.slice.split_at_mut(index);
+        let (fixed, right) = rest.split_first_mut().unwrap();
+        let fixed = &*fixed;
+
+        // The remaining partners are `cur`, `cur + stride`... lower than `end`, the fixed element being skipped
+        let right = right.iter_mut().enumerate().map(|(k, x)| (k + index + 1, x));
+        let mut partners = left.iter_mut().enumerate().chain(right)
+            .filter(|&(k, _)| k >= cur && k < end && (k - cur).is_multiple_of(stride))
+            .map(|(_, x)| x)
+            .collect::<Vec<&mut T>>();
+
+        let workers = std::thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = partners.len().div_ceil(workers).max(1);
+
+        std::thread::scope(|scope| {
+            for chunk in partners.chunks_mut(chunk_size) {
+                let callback = &callback;
+
+                scope.spawn(move || {
+                    for other in chunk.iter_mut() {
+                        callback(fixed, other);
+                    }
+                });
+            }
+        });
+    }
+}
+
+impl<T> crate::ResettableIterator for SingleLineIterator<'_, T> {
+    fn reset(&mut self) {
+        // Just like in the constructor, the fixed element must not be its own first partner
+        self.cur = if self.index == 0 {
+            self.stride
+        } else {
+            0
+        };
+
+        self.end = self.slice.len();
+    }
+}
+
+impl<T> crate::ResettableToIterator for SingleLineIterator<'_, T> {
+    type Position = usize;
+
+    /// Moves the iterator to the partner `slice[cur]`
+    /// 
+    /// # Panics
+    /// Panics in the same cases as `set_cur`
+    fn reset_to(&mut self, cur: usize) {
+        self.set_cur(cur);
+    }
+}
+
+impl<'a, T> Iterator for SingleLineIterator<'a, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur >= self.end {
+            return None;
+        }
+
+        // Both pointers come from the same pointer to the slice, see `DoubleIterator::pair_ptrs`
+        let base = self.slice.as_mut_ptr();
+        let returned = unsafe { (base.add(self.index), base.add(self.cur)) };
+
+        // A stride as large as `usize::MAX` must leave `cur` beyond the end rather than overflow
+        self.cur = self.cur.saturating_add(self.stride);
+        if self.cur == self.index {
+            self.cur = self.cur.saturating_add(self.stride);
+        }
+
+        Some(returned)
+    }
+}
+
+impl<T> DoubleEndedIterator for SingleLineIterator<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The greatest partner lower than `end`, skipping the fixed element
+        let mut last = self.end.checked_sub(1)? / self.stride * self.stride;
+        if last == self.index {
+            last = last.checked_sub(self.stride)?;
+        }
+
+        if last < self.cur {
+            return None;
+        }
+
+        self.end = last;
+        let base = self.slice.as_mut_ptr();
+        Some(unsafe { (base.add(self.index), base.add(last)) })
+    }
+}
+
+// `cur` never decreases and `end` never increases, so they stay crossed once exhausted
+impl<T> FusedIterator for SingleLineIterator<'_, T> {}
+
+impl<T> fmt::Debug for SingleLineIterator<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SingleLineIterator")
+            .field("index", &self.index)
+            .field("cur", &self.cur)
+            .field("len", &self.slice.len())
+            .finish()
+    }
+}
+
+/// Narrows a `DoubleIterator` to the rest of the line of its next pair: the fixed element is `slice[first]` and the next partner is `slice[second]`
+/// 
+/// # Notes
+/// The stride of the `DoubleIterator` is kept, and the line stops at the end of the window and at the back cursor, so the pairs yielded are the ones the `DoubleIterator` would have yielded next on that line. The combinations mode needs nothing more since the line resumes after `second`. None of these bounds but the stride survive a `reset` though: the `SingleLineIterator` then walks the whole line of its fixed element
+/// 
+/// An exhausted `DoubleIterator` gives an empty line over the last member of the slice, so no pointer outside of the slice is ever yielded
+impl<'a, T> From<DoubleIterator<'a, T>> for SingleLineIterator<'a, T> {
+    fn from(src: DoubleIterator<'a, T>) -> Self {
+        let len = src.slice.len();
+
+        // Checks the `exhausted` flag first, like `next` does
+        if src.is_exhausted() {
+            return Self {
+                slice: src.slice,
+                index: len - 1,
+                cur: len,
+                stride: src.step,
+                end: len,
+            };
+        }
+
+        let (_, high) = src.row_bounds(src.first);
+        let mut end = high + 1;
+        if src.back_first == src.first {
+            // The partners after `back_second` have already been yielded by `next_back`
+            end = end.min(src.back_second + 1);
+        }
+
+        Self {
+            cur: src.second,
+            index: src.first,
+            end,
+            slice: src.slice,
+            stride: src.step,
+        }
+    }
+}
+
+/// Widens a `SingleLineIterator` back to the full traversal of `DoubleIterator::new`, the next pair being `(index, cur)`, which is the inverse of the conversion from `DoubleIterator`
+/// 
+/// # Notes
+/// The stride of the `SingleLineIterator` is not kept. If all the partners of the fixed element have been yielded, the `DoubleIterator` starts from the beginning of the next line
+/// 
+/// # Panics
+/// Panics if the slice is shorter than 2, like `DoubleIterator::new`
+impl<'a, T> From<SingleLineIterator<'a, T>> for DoubleIterator<'a, T> {
+    fn from(src: SingleLineIterator<'a, T>) -> Self {
+        let (index, cur) = (src.index, src.cur);
+        let len = src.slice.len();
+        let mut iterator = DoubleIterator::new(src.slice);
+
+        if cur < len {
+            iterator.first = index;
+            iterator.second = cur;
+        } else if index + 1 < len {
+            iterator.first = index + 1;
+            iterator.second = 0;
+        } else {
+            // The last line was exhausted
+            iterator.exhaust();
+        }
+
+        iterator
+    }
+}
+
+/// Yields the pairs of indexes `(i, j)` of a collection of length `len`, following the exact same schedule as `DoubleIterator` but without borrowing any slice
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::IndexPairs;
+/// 
+/// let masses = vec![1, 2, 3];
+/// let mut forces = vec![0; 3];
+/// 
+/// for (i, j) in IndexPairs::new(masses.len()) {
+///     forces[i] += masses[j];
+/// }
+/// 
+/// assert_eq!(forces, vec![5, 4, 3]);
+/// ```
+/// 
+/// It contains no unsafe code and has no lifetime, so it is prefered over `DoubleIterator` when the data is not held in a single mutable slice, like two parallel `Vec`s or an external storage
+#[derive(Clone, Debug)]
+pub struct IndexPairs {
+    len: usize,
+    first: usize,
+    second: usize,
+    unordered: bool,
+}
+
+impl IndexPairs {
+    /// Creates an `IndexPairs` yielding all the distinct ordered pairs of indexes lower than `len`. Nothing is yielded if `len < 2`
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+
+            first: 0,
+            second: 1,
+            unordered: false,
+        }
+    }
+
+    /// Creates an `IndexPairs` yielding each unordered pair of indexes once, as `(i, j)` with `i < j`, just like `DoubleIterator::new_combinations`
+    /// 
+    /// # Example
+    /// ```
+    /// use iterators_collection::share::IndexPairs;
+    /// 
+    /// let pairs = IndexPairs::new_combinations(3).collect::<Vec<(usize, usize)>>();
+    /// assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 2)]);
+    /// ```
+    pub fn new_combinations(len: usize) -> Self {
+        Self {
+            unordered: true,
+            ..Self::new(len)
+        }
+    }
+
+    /// Returns `true` if `self` yields each unordered pair once
+    pub fn is_combinations(&self) -> bool {
+        self.unordered
+    }
+
+    /// Returns the number of pairs left
+    fn remaining(&self) -> usize {
+        let (len, first, second) = (self.len, self.first, self.second);
+        if first >= len || second >= len {
+            return 0;
+        }
+
+        if self.unordered {
+            len * (len - 1) / 2 - (first * (2 * len - first - 1) / 2 + second - first - 1)
+        } else {
+            len * (len - 1) - (first * (len - 1) + second - (second > first) as usize)
+        }
+    }
+}
+
+impl crate::ResettableIterator for IndexPairs {
+    fn reset(&mut self) {
+        self.first = 0;
+        self.second = 1;
+    }
+}
+
+impl Iterator for IndexPairs {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.first >= self.len || self.second >= self.len {
+            return None;
+        }
+
+        let returned = (self.first, self.second);
+
+        self.second += 1;
+        if !self.unordered && self.second == self.first {
+            self.second += 1;
+        }
+
+        if self.second >= self.len {
+            self.first += 1;
+            self.second = if self.unordered { self.first + 1 } else { 0 };
+        }
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for IndexPairs {}
+
+// Once exhausted, `first` stays beyond the end of the collection
+impl FusedIterator for IndexPairs {}
+
+/// Iterates twice over a `Vec` it owns, following the same schedule as `DoubleIterator`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::OwnedDoubleIterator;
+/// 
+/// let iter = OwnedDoubleIterator::new(vec![1, 2, 3]);
+/// let values = iter.safe_for_each(|i, j| *i += *j);
+/// 
+/// assert_eq!(values, vec![6, 11, 20]);
+/// ```
+/// 
+/// Unlike `DoubleIterator`, it doesn't borrow its data, so the collection doesn't need to be kept alive in another binding. The data is given back by `into_inner` or `safe_for_each`
+#[cfg(feature = "alloc")]
+pub struct OwnedDoubleIterator<T> {
+    data: Vec<T>,
+    pairs: IndexPairs,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> OwnedDoubleIterator<T> {
+    /// Creates an `OwnedDoubleIterator` from a `Vec`
+    /// 
+    /// # Panics
+    /// Panics if `data.len() < 2`
+    pub fn new(data: Vec<T>) -> Self {
+        assert!(data.len() >= 2);
+
+        Self {
+            pairs: IndexPairs::new(data.len()),
+            data,
+        }
+    }
+
+    /// Gives back the data, with the changes made through the pointers yielded
+    pub fn into_inner(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Runs the given closure in a safe context on the remaining pairs, then gives back the data
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(mut self, mut callback: F) -> Vec<T> {
+        for (i, j) in &mut self {
+            unsafe {
+                callback(&mut *i, &mut *j);
+            }
+        }
+
+        self.data
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> crate::ResettableIterator for OwnedDoubleIterator<T> {
+    fn reset(&mut self) {
+        self.pairs.reset();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Iterator for OwnedDoubleIterator<T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, second) = self.pairs.next()?;
+        let base = self.data.as_mut_ptr();
+
+        // The indexes are lower than `data.len()`, so the pointers are inside the `Vec`
+        Some(unsafe { (base.add(first), base.add(second)) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pairs.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ExactSizeIterator for OwnedDoubleIterator<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> FusedIterator for OwnedDoubleIterator<T> {}
+
+/// Iterates over the pairs of adjacent members of a slice, `(slice[k], slice[k + 1])` for each `k` in `0..slice.len() - 1`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::AdjacentPairIterator;
+/// 
+/// let mut array = [1, 2, 3, 4];
+/// let iter = AdjacentPairIterator::new(&mut array);
+/// 
+/// iter.safe_for_each(|a, b| *b += *a);
+/// assert_eq!(array, [1, 3, 6, 10]);
+/// ```
+/// 
+/// The prefered way to use this iterator is the `safe_for_each` method, which gives genuine mutable references to the two members since they are always distinct
+pub struct AdjacentPairIterator<'a, T> {
+    slice: &'a mut [T],
+    cur: usize,
+}
+
+impl<'a, T> AdjacentPairIterator<'a, T> {
+    /// Creates an `AdjacentPairIterator` from a slice
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    pub fn new(slice: &'a mut [T]) -> Self {
+        assert!(slice.len() >= 2);
+
+        Self {
+            slice,
+            cur: 0,
+        }
+    }
+
+    /// Runs the given closure on each remaining pair of adjacent members, in the order of the slice
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        for k in self.cur..(self.slice.len() - 1) {
+            let (left, right) = self.slice.split_at_mut(k + 1);
+            callback(&mut left[k], &mut right[0]);
+        }
+    }
+}
+
+impl<T> crate::ResettableIterator for AdjacentPairIterator<'_, T> {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+impl<T> Iterator for AdjacentPairIterator<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur + 1 >= self.slice.len() {
+            return None;
+        }
+
+        let base = self.slice.as_mut_ptr();
+        let returned = unsafe { (base.add(self.cur), base.add(self.cur + 1)) };
+        self.cur += 1;
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len() - 1 - self.cur;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for AdjacentPairIterator<'_, T> {}
+
+// Once exhausted, `cur` stays equal to `slice.len() - 1`
+impl<T> FusedIterator for AdjacentPairIterator<'_, T> {}
+
+/// Iterates over the overlapping windows of `width` consecutive members of a slice, giving mutable access to one window at a time
+/// 
+/// This is the mutable counterpart of `slice::windows`. A standard `Iterator` can't yield the windows since they overlap, so this one is a lending iterator: the slice returned by `next` lives until the next call
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::WindowMutIterator;
+/// 
+/// let mut array = [1, 2, 3, 4, 5];
+/// let iter = WindowMutIterator::new(&mut array, 3);
+/// 
+/// iter.safe_for_each(|window| window[2] += window[0]);
+/// assert_eq!(array, [1, 2, 4, 6, 9]);
+/// ```
+pub struct WindowMutIterator<'a, T> {
+    slice: &'a mut [T],
+    width: usize,
+    cur: usize,
+}
+
+impl<'a, T> WindowMutIterator<'a, T> {
+    /// Creates a `WindowMutIterator` over the windows of `width` members of `slice`
+    /// 
+    /// # Panics
+    /// Panics if `width == 0` or if `width > slice.len()`
+    pub fn new(slice: &'a mut [T], width: usize) -> Self {
+        assert_ne!(width, 0);
+        assert!(width <= slice.len());
+
+        Self {
+            slice,
+            width,
+            cur: 0,
+        }
+    }
+
+    /// Returns the next window, which lives until the next call
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&mut [T]> {
+        if self.cur + self.width > self.slice.len() {
+            return None;
+        }
+
+        let window = &mut self.slice[self.cur..self.cur + self.width];
+        self.cur += 1;
+
+        Some(window)
+    }
+
+    /// Returns the number of windows not yielded yet
+    pub fn len(&self) -> usize {
+        (self.slice.len() + 1).saturating_sub(self.cur + self.width)
+    }
+
+    /// Returns `true` if every window has been yielded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Runs the given closure on each remaining window, in the order of the slice
+    pub fn safe_for_each<F: FnMut(&mut [T])>(mut self, mut callback: F) {
+        while let Some(window) = self.next() {
+            callback(window);
+        }
+    }
+
+    /// Brings the iterator back to its first window
+    pub fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+/// Iterates over the consecutive disjoint pairs of a slice, `(slice[0], slice[1])`, `(slice[2], slice[3])` and so on, just like `slice::chunks_exact_mut(2)` would. If the length of the slice is odd, the last member is never yielded
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::DisjointPairIterator;
+/// 
+/// let mut array = [1, 2, 3, 4, 5];
+/// 
+/// for (a, b) in DisjointPairIterator::new(&mut array) {
+///     std::mem::swap(a, b);
+/// }
+/// assert_eq!(array, [2, 1, 4, 3, 5]);
+/// ```
+/// 
+/// # Notes
+/// Unlike the other iterators of this module, this one yields genuine mutable references since no member belongs to two pairs
+pub struct DisjointPairIterator<'a, T> {
+    rest: &'a mut [T],
+}
+
+impl<'a, T> DisjointPairIterator<'a, T> {
+    /// Creates a `DisjointPairIterator` from a slice
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            rest: slice,
+        }
+    }
+}
+
+impl<'a, T> Iterator for DisjointPairIterator<'a, T> {
+    type Item = (&'a mut T, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.len() < 2 {
+            return None;
+        }
+
+        let (pair, rest) = core::mem::take(&mut self.rest).split_at_mut(2);
+        self.rest = rest;
+
+        let (a, b) = pair.split_at_mut(1);
+        Some((&mut a[0], &mut b[0]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rest.len() / 2;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for DisjointPairIterator<'_, T> {}
+
+// `rest` only gets shorter
+impl<T> FusedIterator for DisjointPairIterator<'_, T> {}
+
+/// Iterates over the self-pairs `(slice[i], slice[i])` skipped by `DoubleIterator`, yielding one single mutable reference for each of them. Used with a `DoubleIterator`, it completes the pass over the whole matrix of the pairs
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::{DiagonalIterator, DoubleIterator};
+/// 
+/// let mut array = [1, 2, 3];
+/// let mut total = 0;
+/// 
+/// DoubleIterator::new(&mut array).safe_for_each(|i, j| total += *i * *j);
+/// DiagonalIterator::new(&mut array).safe_for_each(|x| total += *x * *x);
+/// 
+/// // (1 + 2 + 3) * (1 + 2 + 3)
+/// assert_eq!(total, 36);
+/// ```
+pub struct DiagonalIterator<'a, T> {
+    rest: &'a mut [T],
+}
+
+impl<'a, T> DiagonalIterator<'a, T> {
+    /// Creates a `DiagonalIterator` from a slice
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            rest: slice,
+        }
+    }
+
+    /// Runs the given closure on the member of each remaining self-pair, in the order of the slice
+    pub fn safe_for_each<F: FnMut(&mut T)>(self, callback: F) {
+        self.for_each(callback);
+    }
+}
+
+impl<'a, T> Iterator for DiagonalIterator<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = core::mem::take(&mut self.rest).split_first_mut()?;
+        self.rest = rest;
+
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rest.len(), Some(self.rest.len()))
+    }
+}
+
+impl<T> ExactSizeIterator for DiagonalIterator<'_, T> {}
+
+// `rest` only gets shorter
+impl<T> FusedIterator for DiagonalIterator<'_, T> {}
+
+/// Iterates over a diagonal of the matrix of the pairs of a slice, that is the pairs `(slice[i], slice[i + offset])` for each valid `i`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::DiagonalPairIterator;
+/// 
+/// let mut array = [1, 2, 3, 4, 5];
+/// let iter = DiagonalPairIterator::new(&mut array, 3);
+/// 
+/// iter.safe_for_each(|a, b| std::mem::swap(a, b));
+/// assert_eq!(array, [4, 5, 3, 1, 2]);
+/// ```
+/// 
+/// `AdjacentPairIterator` is the diagonal of offset 1. The offset being never 0, the two members are always distinct, so `safe_for_each` gives genuine mutable references
+pub struct DiagonalPairIterator<'a, T> {
+    slice: &'a mut [T],
+    offset: usize,
+    cur: usize,
+}
+
+impl<'a, T> DiagonalPairIterator<'a, T> {
+    /// Creates a `DiagonalPairIterator` over the pairs `(slice[i], slice[i + offset])`
+    /// 
+    /// # Panics
+    /// Panics if `offset == 0` or if `offset >= slice.len()`
+    pub fn new(slice: &'a mut [T], offset: usize) -> Self {
+        assert_ne!(offset, 0);
+        assert!(offset < slice.len());
+
+        Self {
+            slice,
+            offset,
+            cur: 0,
+        }
+    }
+
+    /// Runs the given closure on each remaining pair, in the order of the slice
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        for k in self.cur..(self.slice.len() - self.offset) {
+            let (left, right) = self.slice.split_at_mut(k + self.offset);
+            callback(&mut left[k], &mut right[0]);
+        }
+    }
+}
+
+impl<T> crate::ResettableIterator for DiagonalPairIterator<'_, T> {
+    fn reset(&mut self) {
+        self.cur = 0;
+    }
+}
+
+impl<T> Iterator for DiagonalPairIterator<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cur + self.offset >= self.slice.len() {
+            return None;
+        }
+
+        let base = self.slice.as_mut_ptr();
+        let returned = unsafe { (base.add(self.cur), base.add(self.cur + self.offset)) };
+        self.cur += 1;
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len() - self.offset - self.cur;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for DiagonalPairIterator<'_, T> {}
+
+// Once exhausted, `cur` stays equal to `slice.len() - offset`
+impl<T> FusedIterator for DiagonalPairIterator<'_, T> {}
+
+/// Iterates twice over the same collection like `DoubleIterator`, but goes over the matrix of the pairs of indexes by square blocks for a better cache efficiency
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::BlockDoubleIterator;
+/// 
+/// let mut array = [0, 1, 2, 3];
+/// let iter = BlockDoubleIterator::new(&mut array, 2);
+/// 
+/// let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+/// assert_eq!(pairs, vec![
+///     (0, 1), (1, 0),                 // Block (0, 0)
+///     (0, 2), (0, 3), (1, 2), (1, 3), // Block (0, 1)
+///     (2, 0), (2, 1), (3, 0), (3, 1), // Block (1, 0)
+///     (2, 3), (3, 2),                 // Block (1, 1)
+/// ]);
+/// ```
+/// 
+/// # Notes
+/// The pairs yielded are the same as the ones of `DoubleIterator::new`, each distinct pair being yielded exactly once, but the order is guaranteed to be the following: the matrix of the pairs `(i, j)` is split into square blocks of `block_size` rows and columns, the blocks at the end of the slice being truncated. The blocks are visited row by row, and the pairs of a block are yielded row by row too, skipping the diagonal
+pub struct BlockDoubleIterator<'a, T> {
+    slice: &'a mut [T],
+    block_size: usize,
+    block_row: usize,
+    block_col: usize,
+    first: usize,
+    second: usize,
+    yielded: usize,
+}
+
+impl<'a, T> BlockDoubleIterator<'a, T> {
+    /// Creates a `BlockDoubleIterator` from a slice and the size of the side of the blocks
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2` or if `block_size == 0`
+    pub fn new(slice: &'a mut [T], block_size: usize) -> Self {
+        assert!(slice.len() >= 2);
+        assert_ne!(block_size, 0);
+
+        let mut iterator = Self {
+            slice,
+            block_size,
+
+            block_row: 0,
+            block_col: 0,
+            first: 0,
+            second: 0,
+            yielded: 0,
+        };
+        crate::ResettableIterator::reset(&mut iterator);
+
+        iterator
+    }
+
+    /// Runs the given closure in a safe context on each remaining pair
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        for (i, j) in self {
+            unsafe {
+                callback(&mut *i, &mut *j);
+            }
+        }
+    }
+
+    /// Moves the cursor to the next cell of the matrix of the pairs, diagonal included, in the block order
+    fn step_cell(&mut self) {
+        let len = self.slice.len();
+        let size = self.block_size;
+
+        self.second += 1;
+        if self.second < ((self.block_col + 1) * size).min(len) {
+            return;
+        }
+
+        // Starts again from the beginning of the next line of the block
+        self.second = self.block_col * size;
+        self.first += 1;
+        if self.first < ((self.block_row + 1) * size).min(len) {
+            return;
+        }
+
+        // Starts again from the beginning of the next block
+        self.block_col += 1;
+        if self.block_col * size >= len {
+            self.block_col = 0;
+            self.block_row += 1;
+        }
+
+        self.first = self.block_row * size;
+        self.second = self.block_col * size;
+    }
+
+    /// Moves the cursor forward until it is not on the diagonal anymore
+    fn skip_diagonal(&mut self) {
+        while self.first < self.slice.len() && self.first == self.second {
+            self.step_cell();
+        }
+    }
+}
+
+impl<T> crate::ResettableIterator for BlockDoubleIterator<'_, T> {
+    fn reset(&mut self) {
+        self.block_row = 0;
+        self.block_col = 0;
+        self.first = 0;
+        self.second = 0;
+        self.yielded = 0;
+
+        self.skip_diagonal();
+    }
+}
+
+impl<T> Iterator for BlockDoubleIterator<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first >= self.slice.len() {
+            return None;
+        }
+
+        let base = self.slice.as_mut_ptr();
+        let returned = unsafe { (base.add(self.first), base.add(self.second)) };
+
+        self.step_cell();
+        self.skip_diagonal();
+        self.yielded += 1;
+
+        Some(returned)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        let remaining = len * (len - 1) - self.yielded;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for BlockDoubleIterator<'_, T> {}
+
+// Once exhausted, `first` stays beyond the end of the slice
+impl<T> FusedIterator for BlockDoubleIterator<'_, T> {}
+
+/// Iterates over the strict upper triangle of the matrix of the pairs of a slice, yielding the indexes `(i, j)` with `i < j` alongside the pointers to `slice[i]` and `slice[j]`
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::UpperTriangleIterator;
+/// 
+/// let mut array = [1, 2, 3];
+/// let mut distances = [[0; 3]; 3];
+/// 
+/// UpperTriangleIterator::new(&mut array).safe_for_each(|i, j, a, b| {
+///     distances[i][j] = *b - *a;
+/// });
+/// 
+/// assert_eq!(distances, [[0, 1, 2], [0, 0, 1], [0, 0, 0]]);
+/// ```
+/// 
+/// It goes over the same pairs as `DoubleIterator::new_combinations`, but the indexes are always reported, which is useful to fill a symmetric matrix of results
+pub struct UpperTriangleIterator<'a, T> {
+    iterator: EnumeratedPairs<'a, T>,
+}
+
+impl<'a, T> UpperTriangleIterator<'a, T> {
+    /// Creates an `UpperTriangleIterator` from a slice
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 2`
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            iterator: DoubleIterator::new_combinations(slice).enumerate_pairs(),
+        }
+    }
+
+    /// Runs the given closure in a safe context, passing it the indexes of the members of the pair before the members themselves
+    pub fn safe_for_each<F: FnMut(usize, usize, &mut T, &mut T)>(self, mut callback: F) {
+        for ((i_index, j_index), (i, j)) in self {
+            unsafe {
+                callback(i_index, j_index, &mut *i, &mut *j);
+            }
+        }
+    }
+}
+
+impl<T> crate::ResettableIterator for UpperTriangleIterator<'_, T> {
+    fn reset(&mut self) {
+        self.iterator.reset();
+    }
+}
+
+impl<T> Iterator for UpperTriangleIterator<'_, T> {
+    type Item = ((usize, usize), (*mut T, *mut T));
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iterator.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iterator.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for UpperTriangleIterator<'_, T> {}
+
+impl<T> FusedIterator for UpperTriangleIterator<'_, T> {}
+
+/// Iterates three times over the same collection, just like `DoubleIterator` does twice
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::TripleIterator;
+/// 
+/// let mut array = [1, 2, 3, 4];
+/// let iter = TripleIterator::new(&mut array);
+/// 
+/// iter.safe_for_each(|a, b, c| {
+///     assert!(a != b && b != c && a != c);
+/// });
+/// ```
+/// 
+/// The three members yielded are always distinct, and the prefered way to use this iterator is the `safe_for_each` method for the same reasons as `DoubleIterator`
+pub struct TripleIterator<'a, T> {
+    slice: &'a mut [T],
+    first: usize,
+    second: usize,
+    third: usize,
+}
+
+impl<'a, T> TripleIterator<'a, T> {
+    /// Creates a `TripleIterator` from a slice
+    /// 
+    /// # Panics
+    /// Panics if `slice.len() < 3`
+    pub fn new(slice: &'a mut [T]) -> Self {
+        assert!(slice.len() >= 3);
+
+        Self {
+            slice,
+
+            first: 0,
+            second: 1,
+            third: 2,
+        }
+    }
+
+    /// Increments the indexes like an odometer, skipping the states where two of them are equal, or returns Err
+    fn increment(&mut self) -> Result<(), ()> {
+        let len = self.slice.len();
+
+        loop {
+            self.third += 1;
+
+            if self.third == len {
+                self.third = 0;
+                self.second += 1;
+
+                if self.second == len {
+                    self.second = 0;
+                    self.first += 1;
+
+                    if self.first >= len {
+                        return Err(());
+                    }
+                }
+            }
+
+            if self.first != self.second && self.second != self.third && self.first != self.third {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Notes
+    /// Not like a legacy iteration using a `for` loop, a, b and c are references because it's safe to use in this context
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T, &mut T)>(self, mut callback: F) {
+        for (a, b, c) in self {
+            unsafe {
+                callback(&mut *a, &mut *b, &mut *c);
+            }
+        }
+    }
+}
+
+impl<T> crate::ResettableIterator for TripleIterator<'_, T> {
+    fn reset(&mut self) {
+        self.first = 0;
+        self.second = 1;
+        self.third = 2;
+    }
+}
+
+impl<T> Iterator for TripleIterator<'_, T> {
+    type Item = (*mut T, *mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first >= self.slice.len() {
+            return None;
+        }
+
+        let base = self.slice.as_mut_ptr();
+        let returned = Some(unsafe { (base.add(self.first), base.add(self.second), base.add(self.third)) });
+        let _ = self.increment(); // The error doesn't matter here
+
+        returned
+    }
+}
+
+// Once exhausted, `first` stays equal to `slice.len()`
+impl<T> FusedIterator for TripleIterator<'_, T> {}
+
+/// Iterates over the cartesian product of two distinct slices
+/// 
+/// # Example
+/// ```
+/// use iterators_collection::share::CrossIterator;
+/// 
+/// let mut left = [1, 2];
+/// let mut right = [10, 20, 30];
+/// let iter = CrossIterator::new(&mut left, &mut right);
+/// 
+/// iter.safe_for_each(|l, r| {
+///     *r += *l;
+/// });
+/// 
+/// assert_eq!(right, [13, 23, 33]);
+/// ```
+/// 
+/// Just like `DoubleIterator`, it returns two raw pointers, the first one to a member of `left` and the second one to a member of `right`, and the prefered way to use it is the `safe_for_each` method
+pub struct CrossIterator<'a, T> {
+    left: &'a mut [T],
+    right: &'a mut [T],
+    cur_left: usize,
+    cur_right: usize,
+}
+
+impl<'a, T> CrossIterator<'a, T> {
+    /// Creates a `CrossIterator` yielding all the `left.len() * right.len()` pairs made of a member of `left` and a member of `right`, in lexicographic order
+    pub fn new(left: &'a mut [T], right: &'a mut [T]) -> Self {
+        Self {
+            left,
+            right,
+            cur_left: 0,
+            cur_right: 0,
+        }
+    }
+
+    /// Runs the given closure in a safe context
+    /// 
+    /// # Notes
+    /// The two slices are distinct mutable borrows, so they can't overlap and handing out two mutable references at the same time is safe
+    pub fn safe_for_each<F: FnMut(&mut T, &mut T)>(self, mut callback: F) {
+        for (l, r) in self {
+            unsafe {
+                callback(&mut *l, &mut *r);
+            }
+        }
+    }
+
+    /// Returns `true` if all the pairs have been yielded
+    fn is_exhausted(&self) -> bool {
+        self.cur_left >= self.left.len() || self.right.is_empty()
+    }
+}
+
+impl<T> crate::ResettableIterator for CrossIterator<'_, T> {
+    fn reset(&mut self) {
+        self.cur_left = 0;
+        self.cur_right = 0;
+    }
+}
+
+impl<T> Iterator for CrossIterator<'_, T> {
+    type Item = (*mut T, *mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_exhausted() {
+            return None;
+        }
+
+        let returned = unsafe {
+            let ptr1 = self.left.get_unchecked_mut(self.cur_left)   as *mut T;
+            let ptr2 = self.right.get_unchecked_mut(self.cur_right) as *mut T;
+
+            Some((ptr1, ptr2))
+        };
+
+        self.cur_right += 1;
+        if self.cur_right == self.right.len() {
+            self.cur_right = 0;
+            self.cur_left += 1;
+        }
+
+        returned
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.is_exhausted() {
+            0
+        } else {
+            (self.left.len() - self.cur_left) * self.right.len() - self.cur_right
+        };
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for CrossIterator<'_, T> {}
+
+// Once exhausted, `cur_left` stays beyond the end of `left`
+impl<T> FusedIterator for CrossIterator<'_, T> {}
+
+/// The error returned by `DoubleIterator::try_new` and `DoubleIterator::safe_for_each_checked` when the slice is too short to contain a pair
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TooShortError {
+    /// The length of the slice
+    pub len: usize,
+}
+
+impl fmt::Display for TooShortError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a slice of length {} is too short to contain a pair, the length must be at least 2", self.len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TooShortError {}
+
+/// The error returned by `SingleLineIterator::try_new` when the index of the fixed element is out of range
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BadIndexError {
+    /// The index given
+    pub index: usize,
+
+    /// The length of the slice
+    pub len: usize,
+}
+
+impl fmt::Display for BadIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "the index {} is out of range for a slice of length {}", self.index, self.len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BadIndexError {}
+
+/// The error returned by `DoubleIterator::try_set` when the position given is not a pair of the traversal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetError {
+    /// Both indexes are equal to the given one
+    EqualIndexes(usize),
+
+    /// The first index is out of range for a slice of length `len`
+    FirstOutOfRange {
+        /// The index given
+        index: usize,
+
+        /// The length of the slice
+        len: usize,
+    },
+
+    /// The second index is out of range for a slice of length `len`
+    SecondOutOfRange {
+        /// The index given
+        index: usize,
+
+        /// The length of the slice
+        len: usize,
+    },
+
+    /// The pair is valid but is skipped by the mode of the iterator, like `(j, i)` with `i < j` in combinations mode
+    NotInTraversal {
+        /// The first index given
+        first: usize,
+
+        /// The second index given
+        second: usize,
+    },
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetError::EqualIndexes(index)              => write!(f, "both indexes of the pair are equal to {}", index),
+            SetError::FirstOutOfRange { index, len }   => write!(f, "the first index {} is out of range for a slice of length {}", index, len),
+            SetError::SecondOutOfRange { index, len }  => write!(f, "the second index {} is out of range for a slice of length {}", index, len),
+            SetError::NotInTraversal { first, second } => write!(f, "({}, {}) is not a pair of the traversal", first, second),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SetError {}
+
+/// The error returned by `DoubleIteratorBuilder::build` when the options are invalid
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BuildError {
+    /// The slice has less than two members
+    TooShort(TooShortError),
+
+    /// The stride is 0
+    ZeroStride,
+
+    /// The stride leaves a single member of a slice of length `len`
+    StrideTooLarge {
+        /// The stride given
+        step: usize,
+
+        /// The length of the slice
+        len: usize,
+    },
+
+    /// The maximum distance is 0
+    ZeroDistance,
+
+    /// The maximum distance is shorter than the stride, so no two multiples of the stride are close enough to make a pair
+    DistanceShorterThanStride {
+        /// The maximum distance given
+        max_distance: usize,
+
+        /// The stride given
+        step: usize,
+    },
+
+    /// The starting pair is not a pair of the configured traversal
+    BadStart(SetError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::TooShort(error)                                  => write!(f, "{}", error),
+            BuildError::ZeroStride                                       => write!(f, "the stride must not be 0"),
+            BuildError::StrideTooLarge { step, len }                     => write!(f, "a stride of {} leaves a single member of a slice of length {}", step, len),
+            BuildError::ZeroDistance                                     => write!(f, "the maximum distance must not be 0"),
+            BuildError::DistanceShorterThanStride { max_distance, step } => write!(f, "a maximum distance of {} is shorter than the stride {}, so no pair can be yielded", max_distance, step),
+            BuildError::BadStart(error)                                  => write!(f, "bad starting pair: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+#[cfg(feature = "alloc")]
+pub mod combinations;
+
+#[cfg(all(test, feature = "std"))]
+mod tests;
+
+#[cfg(all(test, not(feature = "alloc")))]
+mod no_std_tests;
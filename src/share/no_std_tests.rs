@@ -0,0 +1,76 @@
+//! The smoke tests checking the share module works on `core` only, without the `std` and `alloc` features
+
+use crate::share::*;
+use crate::ResettableIterator;
+use core::ops::ControlFlow;
+
+#[test]
+fn double_iterator_visits_every_ordered_pair() {
+    let mut array = [1, 2, 3, 4];
+    let mut count = 0;
+    let mut sum = 0;
+
+    DoubleIterator::new(&mut array).safe_for_each(|a, b| {
+        assert_ne!(*a, *b);
+        count += 1;
+        sum += *a * 10 + *b;
+    });
+
+    assert_eq!(count, 12);
+    assert_eq!(sum, 330);
+}
+
+#[test]
+fn double_iterator_modes_and_reset() {
+    let mut array = [0, 0, 0, 0];
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+    assert_eq!(iter.len(), 6);
+
+    iter.by_ref().for_each(drop);
+    iter.reset();
+    assert_eq!(iter.position(), (0, 1));
+
+    let mut array = [0, 0, 0, 0, 0];
+    assert_eq!(DoubleIterator::with_max_distance(&mut array, 1).count(), 8);
+    assert_eq!(DoubleIterator::with_stride(&mut array, 2).count(), 6);
+}
+
+#[test]
+fn double_iterator_errors_and_early_exit() {
+    let mut one = [1];
+    assert_eq!(DoubleIterator::try_new(&mut one).err(), Some(TooShortError { len: 1 }));
+
+    let mut array = [1, 2, 3];
+    let mut calls = 0;
+    DoubleIterator::new(&mut array).safe_for_each_while(|_, _| {
+        calls += 1;
+        if calls == 2 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+    });
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn single_line_iterator_mutates_the_fixed_element() {
+    let mut array = [1, 2, 3, 4];
+    SingleLineIterator::new(&mut array, 0).safe_for_each(|fixed, other| *fixed += *other);
+    assert_eq!(array, [10, 2, 3, 4]);
+
+    let mut array = [1, 2];
+    assert_eq!(SingleLineIterator::try_new(&mut array, 2).err(), Some(BadIndexError { index: 2, len: 2 }));
+}
+
+#[test]
+fn triple_and_cross_iterators() {
+    let mut array = [1, 2, 3];
+    let mut count = 0;
+    TripleIterator::new(&mut array).safe_for_each(|a, b, c| {
+        assert!(a != b && b != c && a != c);
+        count += 1;
+    });
+    assert_eq!(count, 6);
+
+    let mut left = [1, 2];
+    let mut right = [10, 20, 30];
+    CrossIterator::new(&mut left, &mut right).safe_for_each(|a, b| *a += *b);
+    assert_eq!(left, [61, 62]);
+}
@@ -2,6 +2,8 @@
 
 use crate::share::*;
 use crate::ResettableIterator;
+use crate::ResettableToIterator;
+use std::ops::ControlFlow;
 
 #[test]
 fn double_iterator_never_get_the_same_value() { // What a long name!
@@ -41,6 +43,170 @@ fn double_iterator_does_not_loop() {
     assert!(iter.next().is_none());
 }
 
+#[test]
+fn double_iterator_exact_size() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+    assert_eq!(iter.len(), 20);
+
+    for remaining in (0..20).rev() {
+        iter.next();
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+    }
+
+    iter.set(3, 2);
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.count(), 6);
+}
+
+#[test]
+fn double_iterator_count() {
+    let mut array = [1, 2, 3, 4, 5];
+    let iter = DoubleIterator::new(&mut array);
+
+    assert_eq!(iter.count(), 20);
+}
+
+#[test]
+fn double_iterator_double_ended() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut iter = DoubleIterator::new(&mut array);
+    let mut got = Vec::new();
+
+    // Mixes the calls to `next` and `next_back`
+    for n in 0.. {
+        let pair = if n % 3 == 0 { iter.next() } else { iter.next_back() };
+
+        match pair {
+            Some((i, j)) => unsafe { got.push((*i, *j)) },
+            None         => break,
+        }
+    }
+
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+
+    let mut expected = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array) {
+        unsafe {
+            expected.push((*i, *j));
+        }
+    }
+
+    assert_eq!(got.len(), expected.len());
+    got.sort_unstable();
+    assert_eq!(got, expected);
+}
+
+#[test]
+fn double_iterator_backward() {
+    let mut array = [0, 1, 2];
+    let iter = DoubleIterator::new(&mut array);
+    let pairs = iter.rev().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(usize, usize)>>();
+
+    assert_eq!(pairs, vec![(2, 1), (2, 0), (1, 2), (1, 0), (0, 2), (0, 1)]);
+}
+
+#[test]
+fn double_iterator_len_with_both_ends() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.next_back();
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 9);
+}
+
+#[test]
+fn double_iterator_safe_for_each_batched_with_both_ends() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.set(1, 2);
+    for _ in 0..4 {
+        iter.next_back();
+    }
+
+    let mut batches = Vec::new();
+    iter.safe_for_each_batched::<2, _>(|i, partners| {
+        batches.push((*i, partners.iter().map(|j| **j).collect::<Vec<usize>>()));
+    });
+
+    assert_eq!(batches, vec![(1, vec![2, 3]), (2, vec![0, 1])]);
+}
+
+#[test]
+fn double_iterator_is_fused() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    while iter.next().is_some() {}
+
+    for _ in 0..10 {
+        assert!(iter.next().is_none());
+    }
+}
+
+#[test]
+fn double_iterator_combinations() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+    assert_eq!(iter.len(), 6);
+
+    let pairs = iter.by_ref().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+
+    // The mode survives a reset
+    iter.reset();
+    assert_eq!(iter.count(), 6);
+}
+
+#[test]
+fn double_iterator_combinations_with_both_ends() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+
+    unsafe {
+        let (i, j) = iter.next_back().unwrap();
+        assert_eq!((*i, *j), (2, 3));
+        let (i, j) = iter.next_back().unwrap();
+        assert_eq!((*i, *j), (1, 3));
+    }
+
+    let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3), (1, 2)]);
+}
+
+#[test]
+fn double_iterator_combinations_safe_paths() {
+    let mut array = [0, 1, 2, 3];
+    let mut count = 0;
+    DoubleIterator::new_combinations(&mut array).safe_for_each(|i, j| {
+        assert!(*i < *j);
+        count += 1;
+    });
+    assert_eq!(count, 6);
+
+    let mut partners = Vec::new();
+    DoubleIterator::new_combinations(&mut array).safe_for_each_batched::<2, _>(|i, batch| {
+        partners.push((*i, batch.iter().map(|j| **j).collect::<Vec<i32>>()));
+    });
+    assert_eq!(partners, vec![(0, vec![1, 2]), (0, vec![3]), (1, vec![2, 3]), (2, vec![3])]);
+
+    let mut values = [(0, 0), (1, 0), (2, 0), (3, 0)];
+    DoubleIterator::new_combinations(&mut values).par_safe_for_each_rounds(|i, j| i.1 += j.0);
+    assert_eq!(values, [(0, 6), (1, 5), (2, 3), (3, 0)]);
+
+    let iter = DoubleIterator::new_combinations(&mut array);
+    assert_eq!(iter.task_chunks(2), vec![(0, 1, 1, 2), (1, 2, 4, 0)]);
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_combinations_set_panics_with_reversed_pair() {
+    let mut array = [0, 1, 2, 3];
+    DoubleIterator::new_combinations(&mut array).set(2, 1);
+}
+
 #[test]
 fn double_iterator_safe_for_each() {
     let mut array = [1, 2, 3, 4, 5];
@@ -52,6 +218,345 @@ fn double_iterator_safe_for_each() {
     });
 }
 
+#[test]
+fn double_iterator_with_max_distance() {
+    let mut array = [0, 1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::with_max_distance(&mut array, 1);
+    assert_eq!(iter.len(), 10);
+
+    let pairs = iter.by_ref().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs.len(), 10);
+    for (i, j) in pairs {
+        assert_eq!((i - j).abs(), 1);
+    }
+
+    iter.reset();
+    let mut backward = iter.rev().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    backward.reverse();
+    assert_eq!(backward[..3], [(0, 1), (1, 0), (1, 2)]);
+    assert_eq!(backward.len(), 10);
+}
+
+#[test]
+fn double_iterator_with_max_distance_safe_paths() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut partners = Vec::new();
+    DoubleIterator::with_max_distance(&mut array, 2).safe_for_each_batched::<8, _>(|i, batch| {
+        partners.push((*i, batch.iter().map(|j| **j).collect::<Vec<i32>>()));
+    });
+    assert_eq!(partners, vec![
+        (0, vec![1, 2]),
+        (1, vec![0, 2, 3]),
+        (2, vec![0, 1, 3, 4]),
+        (3, vec![1, 2, 4]),
+        (4, vec![2, 3]),
+    ]);
+
+    let iter = DoubleIterator::with_max_distance(&mut array, 2);
+    assert_eq!(iter.task_chunks(2), vec![(0, 1, 2, 3), (2, 3, 5, 0)]);
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_with_max_distance_set_panics_out_of_window() {
+    let mut array = [0, 1, 2, 3, 4];
+    DoubleIterator::with_max_distance(&mut array, 1).set(0, 2);
+}
+
+#[test]
+fn double_iterator_with_stride() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let mut iter = DoubleIterator::with_stride(&mut array, 3);
+    assert_eq!(iter.len(), 6);
+
+    let pairs = iter.by_ref().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 3), (0, 6), (3, 0), (3, 6), (6, 0), (6, 3)]);
+
+    iter.reset();
+    let backward = iter.rev().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(backward, vec![(6, 3), (6, 0), (3, 6), (3, 0), (0, 6), (0, 3)]);
+
+    let mut partners = Vec::new();
+    DoubleIterator::with_stride(&mut array, 3).safe_for_each_batched::<4, _>(|i, batch| {
+        partners.push((*i, batch.iter().map(|j| **j).collect::<Vec<i32>>()));
+    });
+    assert_eq!(partners, vec![(0, vec![3, 6]), (3, vec![0, 6]), (6, vec![0, 3])]);
+}
+
+#[test]
+fn double_iterator_with_stride_not_dividing_the_length() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+    let iter = DoubleIterator::with_stride(&mut array, 3);
+
+    // 7 is not reached, 6 is
+    let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 3), (0, 6), (3, 0), (3, 6), (6, 0), (6, 3)]);
+
+    let mut array = [0, 1, 2, 3];
+    let strided = DoubleIterator::with_stride(&mut array, 1).map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    let mut array = [0, 1, 2, 3];
+    let plain = DoubleIterator::new(&mut array).map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(strided, plain);
+}
+
+#[test]
+fn double_iterator_try_new() {
+    let mut array = [1, 2];
+    assert!(DoubleIterator::try_new(&mut array).is_ok());
+
+    let mut array = [1];
+    let error = DoubleIterator::try_new(&mut array).err().unwrap();
+    assert_eq!(error, TooShortError { len: 1 });
+    assert_eq!(error.to_string(), "a slice of length 1 is too short to contain a pair, the length must be at least 2");
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_new_panics_with_short_slice() {
+    let mut array: [i32; 0] = [];
+    DoubleIterator::new(&mut array);
+}
+
+#[test]
+fn double_iterator_safe_for_each_accepts_fn_mut() {
+    let mut array = [1, 2, 3, 4];
+    let mut greater = 0;
+
+    DoubleIterator::new(&mut array).safe_for_each(|i, j| if *i > *j {
+        greater += 1;
+    });
+
+    assert_eq!(greater, 6);
+}
+
+#[test]
+fn double_iterator_safe_try_for_each_stops_on_error() {
+    let mut array = [0, 1, 2, 3];
+    let mut calls = 0;
+
+    let result = DoubleIterator::new(&mut array).safe_try_for_each(|i, j| {
+        calls += 1;
+        *i += 10;
+
+        if *j == 2 {
+            Err("found")
+        } else {
+            Ok(())
+        }
+    });
+
+    // (0, 1) then (0, 2) failed
+    assert_eq!(result, Err("found"));
+    assert_eq!(calls, 2);
+    assert_eq!(array, [20, 1, 2, 3]);
+}
+
+#[test]
+fn double_iterator_safe_try_for_each_all_ok() {
+    let mut array = [0, 1, 2];
+    let mut calls = 0;
+
+    let result: Result<(), ()> = DoubleIterator::new(&mut array).safe_try_for_each(|_, _| {
+        calls += 1;
+        Ok(())
+    });
+
+    assert_eq!(result, Ok(()));
+    assert_eq!(calls, 6);
+}
+
+#[test]
+fn double_iterator_safe_for_each_while_stops_early() {
+    let mut array = [0, 1, 2, 3];
+    let mut visited = Vec::new();
+
+    DoubleIterator::new(&mut array).safe_for_each_while(|i, j| {
+        visited.push((*i, *j));
+        *j += 10;
+
+        if visited.len() == 2 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    assert_eq!(visited, vec![(0, 1), (0, 2)]);
+    // The pairs after the break are not visited
+    assert_eq!(array, [0, 11, 12, 3]);
+}
+
+#[test]
+fn double_iterator_safe_for_each_while_continuing_is_safe_for_each() {
+    let mut array = [1, 2, 3];
+    let mut expected = array;
+
+    DoubleIterator::new(&mut array).safe_for_each_while(|i, j| {
+        *i += *j;
+        ControlFlow::Continue(())
+    });
+    DoubleIterator::new(&mut expected).safe_for_each(|i, j| *i += *j);
+
+    assert_eq!(array, expected);
+}
+
+#[test]
+fn double_iterator_safe_for_each_batched() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut batches = Vec::new();
+
+    DoubleIterator::new(&mut array).safe_for_each_batched::<3, _>(|i, partners| {
+        batches.push((*i, partners.iter().map(|j| **j).collect::<Vec<usize>>()));
+    });
+
+    assert_eq!(batches, vec![
+        (0, vec![1, 2, 3]), (0, vec![4]),
+        (1, vec![0, 2, 3]), (1, vec![4]),
+        (2, vec![0, 1, 3]), (2, vec![4]),
+        (3, vec![0, 1, 2]), (3, vec![4]),
+        (4, vec![0, 1, 2]), (4, vec![3]),
+    ]);
+}
+
+#[test]
+fn double_iterator_safe_for_each_batched_from_position() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.set(2, 3);
+
+    let mut batches = Vec::new();
+    iter.safe_for_each_batched::<2, _>(|i, partners| {
+        batches.push((*i, partners.iter().map(|j| **j).collect::<Vec<usize>>()));
+    });
+
+    assert_eq!(batches, vec![(2, vec![3]), (3, vec![0, 1]), (3, vec![2])]);
+}
+
+#[test]
+fn double_iterator_par_safe_for_each_rounds() {
+    let mut sequential = [(1, 0), (2, 0), (3, 0), (4, 0), (5, 0), (6, 0), (7, 0)];
+    let mut parallel = sequential;
+
+    DoubleIterator::new(&mut sequential).safe_for_each(|i, j| i.1 += j.0 * i.0);
+    DoubleIterator::new(&mut parallel).par_safe_for_each_rounds(|i, j| i.1 += j.0 * i.0);
+
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn double_iterator_par_safe_for_each_matches_safe_for_each() {
+    let mut parallel = [(1.0, 0.0), (2.0, 0.0), (4.0, 0.0), (8.0, 0.0), (16.0, 0.0)];
+    let mut sequential = parallel;
+
+    DoubleIterator::new(&mut parallel).par_safe_for_each(|i, j| i.1 += i.0 * j.0);
+    DoubleIterator::new(&mut sequential).safe_for_each(|i, j| i.1 += i.0 * j.0);
+    assert_eq!(parallel, sequential);
+
+    // Only the remaining pairs are processed
+    let mut parallel = [(1, 0), (2, 0), (3, 0), (4, 0)];
+    let mut sequential = parallel;
+
+    let mut iter = DoubleIterator::new(&mut parallel);
+    iter.next();
+    iter.next_back();
+    iter.par_safe_for_each(|i, j| i.1 += j.0);
+
+    let mut iter = DoubleIterator::new(&mut sequential);
+    iter.next();
+    iter.next_back();
+    iter.safe_for_each(|i, j| i.1 += j.0);
+
+    assert_eq!(parallel, sequential);
+}
+
+#[test]
+fn round_robin_rounds_are_disjoint() {
+    for len in 2..9 {
+        let mut pairs = Vec::new();
+
+        for round in round_robin(len) {
+            let mut members = round.iter().flat_map(|&(i, j)| vec![i, j]).collect::<Vec<usize>>();
+            let count = members.len();
+            members.sort_unstable();
+            members.dedup();
+            assert_eq!(members.len(), count);
+
+            pairs.extend(round.iter().map(|&(i, j)| (i.min(j), i.max(j))));
+        }
+
+        // Every unordered pair is scheduled once
+        assert_eq!(pairs.len(), len * (len - 1) / 2);
+        pairs.sort_unstable();
+        pairs.dedup();
+        assert_eq!(pairs.len(), len * (len - 1) / 2);
+    }
+}
+
+#[test]
+fn double_iterator_safe_for_each_budget() {
+    let mut array = [0, 0, 0, 0];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    assert_eq!(iter.safe_for_each_budget(5, |i, _j| *i += 1), 5);
+    assert_eq!(iter.safe_for_each_budget(5, |i, _j| *i += 1), 5);
+    assert_eq!(iter.safe_for_each_budget(5, |i, _j| *i += 1), 2);
+    assert_eq!(iter.safe_for_each_budget(5, |i, _j| *i += 1), 0);
+
+    assert_eq!(array, [3, 3, 3, 3]);
+}
+
+#[test]
+fn double_iterator_safe_for_each_with_stats() {
+    let mut array = [1.0, 2.0, 4.0];
+    let iter = DoubleIterator::new(&mut array);
+
+    let stats = iter.safe_for_each_with_stats(|i, j| *j - *i);
+
+    assert_eq!(stats.count(), 6);
+    assert_eq!(stats.sum(), 0.0);
+    assert_eq!(stats.min(), Some(-3.0));
+    assert_eq!(stats.max(), Some(3.0));
+    assert_eq!(stats.mean(), Some(0.0));
+}
+
+#[test]
+fn double_iterator_safe_for_each_with_stats_without_pairs() {
+    let mut array = [1.0, 2.0];
+    let mut iter = DoubleIterator::new(&mut array);
+    while iter.next().is_some() {}
+
+    let stats = iter.safe_for_each_with_stats(|_i, _j| 1.0);
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.sum(), 0.0);
+    assert_eq!(stats.min(), None);
+    assert_eq!(stats.max(), None);
+    assert_eq!(stats.mean(), None);
+}
+
+#[test]
+fn double_iterator_relax_pairs() {
+    let mut array = [0.0, 2.0, 6.0];
+    let mut pairs = Vec::new();
+
+    let change = DoubleIterator::new(&mut array).relax_pairs(|i, j| {
+        pairs.push((*i, *j));
+        (*j - *i) / 2.0
+    });
+
+    assert_eq!(pairs, vec![(0.0, 2.0), (0.0, 6.0), (2.0, 6.0)]);
+    assert_eq!(change, 3.0);
+}
+
+#[test]
+fn double_iterator_for_each_ref() {
+    let mut array = [0, 1, 2];
+    let mut pairs = Vec::new();
+
+    DoubleIterator::new(&mut array).for_each_ref(|i, j| pairs.push((*i, *j)));
+
+    assert_eq!(pairs, vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]);
+}
+
 #[test]
 fn double_iterator_reset() {
     let mut array = [1, 2, 3, 4, 5];
@@ -75,55 +580,572 @@ fn double_iterator_set() {
 }
 
 #[test]
-#[should_panic]
-fn double_iterator_set_panics_with_same_values() {
-    let mut array = [1, 2, 3, 4, 5];
+fn double_iterator_position() {
+    let mut array = [0, 1, 2, 3, 4];
     let mut iter = DoubleIterator::new(&mut array);
+    assert_eq!(iter.position(), (0, 1));
 
-    // We could write other values
-    iter.set(3, 3);
+    iter.set(2, 4);
+    assert_eq!(iter.position(), (2, 4));
+
+    iter.next();
+    assert_eq!(iter.position(), (3, 0));
 }
 
 #[test]
-#[should_panic]
-fn double_iterator_set_panics_for_overflow() {
-    let mut array = [1, 2, 3, 4, 5];
+fn double_iterator_reset_to() {
+    let mut array = [0, 1, 2, 3];
     let mut iter = DoubleIterator::new(&mut array);
+    while iter.next_back().is_some() {}
 
-    iter.set(5, 4);
+    iter.reset_to((2, 1));
+    assert_eq!(iter.len(), 5);
+
+    let (i, j) = iter.next().unwrap();
+    unsafe {
+        assert_eq!((*i, *j), (2, 1));
+    }
 }
 
 #[test]
-fn single_line_iterator_iterates_well() {
-    let mut slice = [1, 2, 3, 4, 5];
-
-    for n in 0..slice.len() {
-        let iter = SingleLineIterator::new(&mut slice, n);
+fn double_iterator_from_pair_yields_a_suffix() {
+    let mut array = [0, 1, 2, 3];
+    let mut full = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array) {
+        unsafe {
+            full.push((*i, *j));
+        }
+    }
 
-        for (i, j) in iter {
+    for (n, &(i, j)) in full.iter().enumerate() {
+        let mut suffix = Vec::new();
+        for (a, b) in DoubleIterator::new(&mut array).from_pair(i, j) {
             unsafe {
-                assert_eq!(n + 1, *i);
-                assert_ne!(i, j);
+                suffix.push((*a, *b));
             }
         }
+
+        assert_eq!(suffix, full[n..]);
     }
 }
 
 #[test]
-#[should_panic]
-fn single_line_iterator_new_panics_when_out_of_range() {
-    let mut slice = [1, 2, 3, 4, 5];
-    let _iter = SingleLineIterator::new(&mut slice, 5);
+fn double_iterator_iter_from() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    assert_eq!(iter.iter_from(3, 1).count(), 2);
+    assert_eq!(iter.iter_from(2, 3).count(), 4);
 }
 
 #[test]
-fn single_line_iterator_from_double_iterator_works() {
-    let mut slice = [1, 2, 3, 4, 5];
-    let mut iter = DoubleIterator::new(&mut slice);
-    iter.set(0, 3);
+#[should_panic]
+fn double_iterator_set_panics_with_same_values() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
 
-    let iter = SingleLineIterator::from(iter);
-    let expected = vec![(1, 4), (1, 5)];
+    // We could write other values
+    iter.set(3, 3);
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_set_panics_for_overflow() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.set(5, 4);
+}
+
+#[test]
+fn double_iterator_try_mutate_pairs_rolls_back() {
+    let mut array = [0, 10, 20];
+    let iter = DoubleIterator::new(&mut array);
+
+    // Only the transfers leaving a positive value are kept
+    iter.try_mutate_pairs(|i, j| {
+        *i -= 5;
+        *j += 5;
+        *i >= 0
+    });
+
+    // The two transfers from 0 are rolled back
+    assert_eq!(array, [10, 5, 15]);
+}
+
+#[test]
+fn double_iterator_try_mutate_pairs_keeps_everything() {
+    let mut array = [1, 2, 3];
+    let iter = DoubleIterator::new(&mut array);
+
+    iter.try_mutate_pairs(|i, _j| {
+        *i += 1;
+        true
+    });
+
+    assert_eq!(array, [3, 4, 5]);
+}
+
+#[test]
+fn double_iterator_safe_for_each_seeded() {
+    let mut array = [0, 1, 2, 3];
+    let mut seeds = Vec::new();
+
+    DoubleIterator::new(&mut array).safe_for_each_seeded(|i, j, seed| {
+        assert_eq!(seed, pair_seed(*i, *j));
+        seeds.push(seed);
+    });
+
+    // Every pair got its own seed
+    let count = seeds.len();
+    seeds.sort_unstable();
+    seeds.dedup();
+    assert_eq!(seeds.len(), count);
+}
+
+#[test]
+fn pair_seed_is_stable() {
+    assert_eq!(pair_seed(0, 0), 0xA706_DD2F_4D19_7E6F);
+    assert_eq!(pair_seed(3, 4), pair_seed(3, 4));
+    assert_ne!(pair_seed(3, 4), pair_seed(4, 3));
+}
+
+#[test]
+fn double_iterator_per_element_sum() {
+    let array = [1.0, 2.0, 4.0, 8.0];
+    let calls = std::cell::Cell::new(0);
+
+    let sums = DoubleIterator::per_element_sum(&array, |i, j| {
+        calls.set(calls.get() + 1);
+        j - i
+    });
+
+    // Computed once per unordered pair
+    assert_eq!(calls.get(), 6);
+    assert_eq!(sums, vec![1.0 + 3.0 + 7.0, 1.0 + 2.0 + 6.0, 3.0 + 2.0 + 4.0, 7.0 + 6.0 + 4.0]);
+}
+
+#[test]
+fn double_iterator_adjacency_matrix() {
+    let array = [1, 2, 3, 4];
+    let calls = std::cell::Cell::new(0);
+
+    // Not symmetric on purpose: only `i < j` is evaluated
+    let matrix = DoubleIterator::adjacency_matrix(&array, |i, j| {
+        calls.set(calls.get() + 1);
+        j % i == 0
+    });
+
+    assert_eq!(calls.get(), 6);
+    assert_eq!(matrix, vec![
+        vec![false, true, true, true],
+        vec![true, false, false, true],
+        vec![true, false, false, false],
+        vec![true, true, false, false],
+    ]);
+}
+
+#[test]
+fn double_iterator_top_k_pairs() {
+    let array: [i32; 5] = [1, 8, 3, 20, 9];
+
+    let smallest = DoubleIterator::top_k_pairs(&array, 3, |i, j| (i - j).abs(), false);
+    assert_eq!(smallest, vec![((1, 4), 1), ((0, 2), 2), ((1, 2), 5)]);
+
+    let largest = DoubleIterator::top_k_pairs(&array, 2, |i, j| (i - j).abs(), true);
+    assert_eq!(largest, vec![((0, 3), 19), ((2, 3), 17)]);
+
+    assert_eq!(DoubleIterator::top_k_pairs(&array, 20, |i, j| (i - j).abs(), false).len(), 10);
+}
+
+#[test]
+fn double_iterator_broad_phase() {
+    let array = [1, 12, 5, 14, 3, 27, 16];
+
+    let candidates = DoubleIterator::broad_phase(&array, |x| x / 10);
+    assert_eq!(candidates, vec![(0, 2), (0, 4), (1, 3), (1, 6), (2, 4), (3, 6)]);
+}
+
+#[test]
+fn dirty_pairs_skips_clean_pairs() {
+    let mut array = [0, 1, 2, 3];
+    let dirty = [false, true, false, true];
+    let mut got = Vec::new();
+
+    for (i, j) in DoubleIterator::new(&mut array).dirty_pairs(&dirty) {
+        unsafe {
+            got.push((*i, *j));
+        }
+    }
+
+    assert_eq!(got, vec![
+        (0, 1), (0, 3),
+        (1, 0), (1, 2), (1, 3),
+        (2, 1), (2, 3),
+        (3, 0), (3, 1), (3, 2),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn dirty_pairs_panics_with_wrong_length() {
+    let mut array = [0, 1, 2, 3];
+    let _iter = DoubleIterator::new(&mut array).dirty_pairs(&[true, false]);
+}
+
+#[test]
+fn merge_schedules_covers_every_shard() {
+    let mut array = [0, 1, 2, 3];
+    let mut full = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array) {
+        unsafe {
+            full.push((*i, *j));
+        }
+    }
+
+    // Splits the schedule into shards of even and odd first index
+    let even = full.iter().cloned().filter(|x| x.0 % 2 == 0).collect::<Vec<(usize, usize)>>();
+    let odd = full.iter().cloned().filter(|x| x.0 % 2 == 1).collect::<Vec<(usize, usize)>>();
+
+    let mut merged = merge_schedules(vec![even, odd]).collect::<Vec<(usize, usize)>>();
+    merged.sort_unstable();
+    assert_eq!(merged, full);
+}
+
+#[test]
+fn double_iterator_task_chunks_tile_the_pairs() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut full = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array) {
+        unsafe {
+            full.push((*i, *j));
+        }
+    }
+
+    let iter = DoubleIterator::new(&mut array);
+    let chunks = iter.task_chunks(3);
+    assert_eq!(chunks.len(), 3);
+
+    // Each chunk starts where the previous one stops
+    assert_eq!((chunks[0].0, chunks[0].1), (0, 1));
+    for n in 1..chunks.len() {
+        assert_eq!((chunks[n - 1].2, chunks[n - 1].3), (chunks[n].0, chunks[n].1));
+    }
+    assert_eq!((chunks[2].2, chunks[2].3), (5, 0));
+
+    let mut covered = Vec::new();
+    for (start_first, start_second, end_first, end_second) in chunks {
+        let begin = full.iter().position(|x| *x == (start_first, start_second)).unwrap();
+        let end = full.iter().position(|x| *x == (end_first, end_second)).unwrap_or(full.len());
+
+        assert!(end - begin >= 6);
+        covered.extend_from_slice(&full[begin..end]);
+    }
+
+    assert_eq!(covered, full);
+}
+
+#[test]
+fn double_iterator_task_chunks_with_more_chunks_than_pairs() {
+    let mut array = [0, 1];
+    let iter = DoubleIterator::new(&mut array);
+
+    assert_eq!(iter.task_chunks(3), vec![(0, 1, 1, 0), (1, 0, 2, 0), (2, 0, 2, 0)]);
+}
+
+#[test]
+fn masked_pairs_replays_the_mask() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut mask = vec![0; 2];
+
+    // Enables the pairs (0, 1), (1, 0) and (9, 8) which are the first, the 10th and the last ones
+    mask[0] |= 1;
+    mask[0] |= 1 << 9;
+    mask[1] |= 1 << (89 - 64);
+
+    let mut got = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array).with_pair_mask(mask) {
+        unsafe {
+            got.push((*i, *j));
+        }
+    }
+
+    assert_eq!(got, vec![(0, 1), (1, 0), (9, 8)]);
+}
+
+#[test]
+fn masked_pairs_drain_in_every_mode() {
+    let mut array = [0, 1, 2, 3, 4];
+
+    // Every pair enabled, so the masked traversal is the plain one
+    let full = vec![u64::MAX];
+    type Mode = fn(&mut [i32]) -> DoubleIterator<'_, i32>;
+    let modes: [Mode; 4] = [
+        |slice| DoubleIterator::new(slice),
+        |slice| DoubleIterator::new_combinations(slice),
+        |slice| DoubleIterator::with_stride(slice, 2),
+        |slice| DoubleIterator::with_max_distance(slice, 1),
+    ];
+
+    for mode in modes.iter() {
+        let expected = mode(&mut array).collect_index_pairs();
+
+        let mut iter = mode(&mut array).with_pair_mask(full.clone());
+        assert_eq!(iter.by_ref().count(), expected.len());
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    // Only the first and the last pairs of the combinations
+    let mut iter = DoubleIterator::new_combinations(&mut array).with_pair_mask(vec![1 | 1 << 9]);
+    let base = iter.next().unwrap().0;
+    let (i, j) = iter.next().unwrap();
+    assert_eq!(unsafe { (i.offset_from(base), j.offset_from(base)) }, (3, 4));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn masked_pairs_panics_with_short_mask() {
+    let mut array = [0; 9];
+    let _iter = DoubleIterator::new(&mut array).with_pair_mask(vec![0]);
+}
+
+#[test]
+fn enumerated_pairs_match_the_pointers() {
+    let mut array = [0, 1, 2, 3];
+    let base = array.as_ptr();
+    let iter = DoubleIterator::new(&mut array).enumerate_pairs();
+    assert_eq!(iter.len(), 12);
+
+    for ((i, j), (a, b)) in iter {
+        unsafe {
+            assert_eq!(a as *const i32, base.add(i));
+            assert_eq!(b as *const i32, base.add(j));
+        }
+    }
+}
+
+#[test]
+fn double_iterator_batched() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array).batched(5);
+    assert_eq!(iter.len(), 3);
+
+    let first = iter.next().unwrap();
+    assert_eq!(first, vec![(0, 1), (0, 2), (0, 3), (1, 0), (1, 2)]);
+
+    // 12 pairs: a full batch of 5, another one, then the remaining 2
+    let rest = iter.by_ref().collect::<Vec<Vec<(usize, usize)>>>();
+    assert_eq!(rest.iter().map(|batch| batch.len()).sum::<usize>(), 7);
+    assert_eq!(rest.iter().map(|batch| batch.len()).collect::<Vec<usize>>(), vec![5, 2]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    assert_eq!(iter.next(), Some(first));
+
+    // Same pairs as the plain traversal
+    let mut array = [0; 5];
+    let batched = DoubleIterator::new_combinations(&mut array).batched(3).flatten().collect::<Vec<(usize, usize)>>();
+    assert_eq!(batched, DoubleIterator::new_combinations(&mut array).collect_index_pairs());
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_batched_rejects_empty_batches() {
+    let mut array = [0, 1];
+    let _ = DoubleIterator::new(&mut array).batched(0);
+}
+
+#[test]
+fn double_iterator_safe_for_each_indexed() {
+    let mut array = [0, 1, 2, 3];
+    let mut count = 0;
+
+    DoubleIterator::new(&mut array).safe_for_each_indexed(|i_index, j_index, i, j| {
+        assert_eq!((*i, *j), (i_index, j_index));
+        count += 1;
+    });
+
+    assert_eq!(count, 12);
+}
+
+#[test]
+fn lending_double_iterator_mutates_through_references() {
+    let mut array = [1, 2, 3];
+    let mut iter = LendingDoubleIterator::new(&mut array);
+    assert_eq!(iter.remaining(), 6);
+
+    let mut visited = Vec::new();
+    while let Some((i, j)) = iter.next() {
+        visited.push((*i, *j));
+        *i += 1;
+    }
+    // Each mutation is seen by the next pairs
+    assert_eq!(visited, vec![(1, 2), (2, 3), (2, 3), (3, 3), (3, 3), (4, 4)]);
+    assert!(iter.next().is_none());
+
+    iter.reset();
+    assert_eq!(iter.remaining(), 6);
+
+    assert_eq!(array, [3, 4, 5]);
+}
+
+#[test]
+fn permuted_pairs_follow_the_permutation() {
+    let mut array = [0, 1, 2, 3];
+    let perm = vec![3, 1, 0, 2];
+    let mut got = Vec::new();
+
+    for (i, j) in DoubleIterator::with_index_permutation(&mut array, perm.clone()) {
+        unsafe {
+            got.push((*i, *j));
+        }
+    }
+
+    let mut expected = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array) {
+        unsafe {
+            expected.push((perm[*i], perm[*j]));
+        }
+    }
+
+    assert_eq!(got, expected);
+}
+
+#[test]
+#[should_panic]
+fn permuted_pairs_panics_with_invalid_permutation() {
+    let mut array = [0, 1, 2, 3];
+    let _iter = DoubleIterator::with_index_permutation(&mut array, vec![0, 1, 1, 2]);
+}
+
+#[test]
+fn ordered_pairs_from_both_ends() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut iter = DoubleIterator::new(&mut array).ordered_pairs();
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+
+    while let Some((i, j)) = iter.next() {
+        unsafe {
+            front.push((*i, *j));
+        }
+
+        match iter.next_back() {
+            Some((i, j)) => unsafe { back.push((*i, *j)) },
+            None         => break,
+        }
+    }
+
+    assert_eq!(front, vec![(0, 1), (0, 2), (0, 3), (0, 4), (1, 2)]);
+    assert_eq!(back, vec![(3, 4), (2, 4), (2, 3), (1, 4), (1, 3)]);
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn ordered_pairs_backward() {
+    let mut array = [0, 1, 2];
+    let iter = DoubleIterator::new(&mut array).ordered_pairs();
+    let pairs = iter.rev().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(usize, usize)>>();
+
+    assert_eq!(pairs, vec![(1, 2), (0, 2), (0, 1)]);
+}
+
+#[test]
+fn z_order_yields_the_same_pairs() {
+    type Mode = fn(&mut [i32]) -> DoubleIterator<'_, i32>;
+    let modes: [Mode; 4] = [
+        |slice| DoubleIterator::new(slice),
+        |slice| DoubleIterator::new_combinations(slice),
+        |slice| DoubleIterator::with_stride(slice, 2),
+        |slice| DoubleIterator::with_max_distance(slice, 1),
+    ];
+
+    let mut array = [0, 1, 2, 3, 4];
+    for mode in modes.iter() {
+        let all = mode(&mut array).collect_index_pairs();
+
+        // From every position, with a back cursor moved too
+        for skipped in 0..=all.len() {
+            let base = array.as_mut_ptr();
+            let mut iter = mode(&mut array);
+            iter.by_ref().take(skipped).for_each(drop);
+            let back = iter.next_back().is_some() as usize;
+
+            let mut expected = all[skipped..all.len() - back].to_vec();
+
+            let mut z_order = iter.z_order();
+            assert_eq!(z_order.len(), expected.len());
+
+            let mut got = z_order.by_ref().map(|(i, j)| unsafe { (i.offset_from(base) as usize, j.offset_from(base) as usize) }).collect::<Vec<(usize, usize)>>();
+            assert_eq!(z_order.len(), 0);
+            assert!(z_order.next().is_none());
+
+            expected.sort_unstable();
+            got.sort_unstable();
+            assert_eq!(got, expected);
+
+            // Back to the whole traversal of the mode
+            z_order.reset();
+            assert_eq!(z_order.count(), all.len());
+        }
+    }
+
+    let mut array = [0; 5];
+    assert_eq!(DoubleIterator::new_combinations(&mut array).z_order().count(), 10);
+    assert_eq!(DoubleIterator::with_stride(&mut array, 2).z_order().count(), 6);
+
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.nth(10);
+    assert_eq!(iter.z_order().count(), 9);
+}
+
+#[test]
+fn z_order_follows_morton_codes() {
+    let mut array = [0, 1, 2, 3];
+    let mut got = Vec::new();
+    for (i, j) in DoubleIterator::new(&mut array).z_order() {
+        unsafe {
+            got.push((*i, *j));
+        }
+    }
+
+    assert_eq!(got[..4], [(0, 1), (1, 0), (0, 2), (0, 3)]);
+}
+
+#[test]
+fn single_line_iterator_iterates_well() {
+    let mut slice = [1, 2, 3, 4, 5];
+
+    for n in 0..slice.len() {
+        let iter = SingleLineIterator::new(&mut slice, n);
+
+        for (i, j) in iter {
+            unsafe {
+                assert_eq!(n + 1, *i);
+                assert_ne!(i, j);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn single_line_iterator_new_panics_when_out_of_range() {
+    let mut slice = [1, 2, 3, 4, 5];
+    let _iter = SingleLineIterator::new(&mut slice, 5);
+}
+
+#[test]
+fn single_line_iterator_from_double_iterator_works() {
+    let mut slice = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut slice);
+    iter.set(0, 3);
+
+    let iter = SingleLineIterator::from(iter);
+    let expected = [(1, 4), (1, 5)];
 
     let mut last_n = 0;
     for (n, i) in iter.enumerate() {
@@ -148,3 +1170,1634 @@ fn single_line_iterator_safe_for_each() {
         assert_ne!(i, j);
     });
 }
+
+#[test]
+fn single_line_iterator_safe_for_each_accepts_fn_mut() {
+    let mut array = [1, 2, 3, 4];
+    let mut sum = 0;
+
+    SingleLineIterator::new(&mut array, 1).safe_for_each(|_, j| sum += *j);
+    assert_eq!(sum, 8);
+}
+
+#[test]
+fn single_line_iterator_safe_try_for_each() {
+    let mut array = [0, 1, 2, 3];
+    let mut visited = Vec::new();
+
+    let result = SingleLineIterator::new(&mut array, 0).safe_try_for_each(|_, j| {
+        visited.push(*j);
+        if *j == 2 { Err(*j) } else { Ok(()) }
+    });
+    assert_eq!(result, Err(2));
+    assert_eq!(visited, vec![1, 2]);
+
+    let result: Result<(), i32> = SingleLineIterator::new(&mut array, 0).safe_try_for_each(|_, _| Ok(()));
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn single_line_iterator_position() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+    assert_eq!(iter.position(), (1, 0));
+
+    iter.next();
+    assert_eq!(iter.position(), (1, 2));
+}
+
+#[test]
+fn single_line_iterator_try_new() {
+    let mut array = [1, 2, 3];
+    assert_eq!(SingleLineIterator::try_new(&mut array, 2).unwrap().count(), 2);
+
+    let error = SingleLineIterator::try_new(&mut array, 3).err().unwrap();
+    assert_eq!(error, BadIndexError { index: 3, len: 3 });
+    assert_eq!(error.to_string(), "the index 3 is out of range for a slice of length 3");
+}
+
+#[test]
+fn single_line_iterator_reset_to() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+    while iter.next().is_some() {}
+
+    iter.reset_to(2);
+    let (i, j) = iter.next().unwrap();
+    unsafe {
+        assert_eq!((*i, *j), (1, 2));
+    }
+    assert_eq!(iter.count(), 1);
+}
+
+#[test]
+fn single_line_iterator_reset_with_index_zero() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 0);
+    while iter.next().is_some() {}
+
+    iter.reset();
+    let (i, j) = iter.next().unwrap();
+    assert_ne!(i, j);
+    unsafe {
+        assert_eq!((*i, *j), (0, 1));
+    }
+
+    let mut array = [0, 1, 2, 3, 4];
+    let mut iter = SingleLineIterator::with_stride(&mut array, 0, 2);
+    iter.next();
+    iter.reset();
+    assert_eq!(iter.position(), (0, 2));
+}
+
+#[test]
+fn single_line_iterator_set_cur() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+
+    iter.set_cur(3);
+    let (i, j) = iter.next().unwrap();
+    unsafe {
+        assert_eq!((*i, *j), (1, 3));
+    }
+    assert_eq!(iter.count(), 1);
+}
+
+#[test]
+#[should_panic]
+fn single_line_iterator_set_cur_panics_on_the_fixed_element() {
+    let mut array = [0, 1, 2];
+    SingleLineIterator::new(&mut array, 1).set_cur(1);
+}
+
+#[test]
+#[should_panic]
+fn single_line_iterator_set_cur_panics_when_out_of_range() {
+    let mut array = [0, 1, 2];
+    SingleLineIterator::new(&mut array, 1).set_cur(3);
+}
+
+#[test]
+fn single_line_iterator_set_index() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 2);
+    iter.next();
+
+    iter.set_index(0);
+    let pairs = iter.by_ref().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 1), (0, 2), (0, 3)]);
+
+    iter.set_index(3);
+    let pairs = iter.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(3, 0), (3, 1), (3, 2)]);
+}
+
+#[test]
+fn single_line_iterator_double_ended() {
+    let mut array = [0, 1, 2, 3, 4, 5];
+    let mut iter = SingleLineIterator::new(&mut array, 2);
+    let mut visited = Vec::new();
+
+    unsafe {
+        visited.push(*iter.next_back().unwrap().1);
+        visited.push(*iter.next().unwrap().1);
+        visited.push(*iter.next_back().unwrap().1);
+        visited.push(*iter.next().unwrap().1);
+        visited.push(*iter.next_back().unwrap().1);
+    }
+
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+    assert_eq!(visited, vec![5, 0, 4, 1, 3]);
+
+    iter.reset();
+    let backward = iter.rev().map(|(_, j)| unsafe { *j }).collect::<Vec<i32>>();
+    assert_eq!(backward, vec![5, 4, 3, 1, 0]);
+}
+
+#[test]
+fn single_line_iterator_double_ended_with_stride() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+    let iter = SingleLineIterator::with_stride(&mut array, 6, 3);
+
+    let backward = iter.rev().map(|(_, j)| unsafe { *j }).collect::<Vec<i32>>();
+    assert_eq!(backward, vec![3, 0]);
+}
+
+#[test]
+fn single_line_iterator_with_stride() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let iter = SingleLineIterator::with_stride(&mut array, 4, 2);
+
+    let mut partners = Vec::new();
+    for (i, j) in iter {
+        unsafe {
+            assert_eq!(*i, 4);
+            partners.push(*j);
+        }
+    }
+
+    // 4 is a multiple of 2 but it is the fixed index
+    assert_eq!(partners, vec![0, 2, 6, 8]);
+}
+
+#[test]
+fn single_line_iterator_with_stride_larger_than_the_slice() {
+    for &stride in &[4, 5, usize::MAX] {
+        let mut array = [0u8, 1, 2, 3];
+        let mut iter = SingleLineIterator::with_stride(&mut array, 1, stride);
+
+        // Only the partner 0 is a multiple of `stride`
+        assert_eq!(iter.next().map(|(_, j)| unsafe { *j }), Some(0));
+        for _ in 0..3 {
+            assert!(iter.next().is_none());
+        }
+    }
+
+    let mut array = [0u8; 4];
+    let mut iter = SingleLineIterator::with_stride(&mut array, 0, usize::MAX);
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn single_line_iterator_with_stride_panics_with_zero_stride() {
+    let mut array = [1, 2, 3, 4, 5];
+    let _iter = SingleLineIterator::with_stride(&mut array, 0, 0);
+}
+
+#[test]
+fn single_line_iterator_safe_for_each_both() {
+    let mut array = [10, 1, 2, 3, 4];
+    let mut seen = Vec::new();
+
+    SingleLineIterator::new(&mut array, 0).safe_for_each_both(|fixed, partner| {
+        // The mutations of the fixed element persist
+        seen.push(*fixed);
+        *fixed += 1;
+        *partner *= 2;
+    });
+
+    assert_eq!(seen, vec![10, 11, 12, 13]);
+    assert_eq!(array, [14, 2, 4, 6, 8]);
+}
+
+#[test]
+fn single_line_iterator_reduce_into_fixed() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut order = Vec::new();
+
+    SingleLineIterator::new(&mut array, 2).reduce_into_fixed(|fixed, partner| {
+        order.push(*partner);
+        *fixed += partner;
+    });
+
+    assert_eq!(order, vec![0, 1, 3, 4]);
+    assert_eq!(array, [0, 1, 10, 3, 4]);
+}
+
+#[test]
+fn single_line_iterator_is_fused() {
+    let mut array = [1, 2, 3];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+    while iter.next().is_some() {}
+
+    for _ in 0..10 {
+        assert!(iter.next().is_none());
+    }
+}
+
+#[test]
+fn triple_iterator_get_all_the_possible_values() {
+    let mut array = [0, 1, 2, 3];
+    let mut iter = TripleIterator::new(&mut array);
+
+    let triples = iter.by_ref().map(|(a, b, c)| unsafe { (*a, *b, *c) }).collect::<Vec<(i32, i32, i32)>>();
+    assert_eq!(triples.len(), 4 * 3 * 2);
+    assert_eq!(triples[..3], [(0, 1, 2), (0, 1, 3), (0, 2, 1)]);
+
+    for (a, b, c) in triples.iter() {
+        assert!(a != b && b != c && a != c);
+    }
+
+    iter.reset();
+    assert_eq!(iter.count(), 24);
+}
+
+#[test]
+fn triple_iterator_safe_for_each() {
+    let mut array = [0, 0, 0];
+    let mut calls = 0;
+    TripleIterator::new(&mut array).safe_for_each(|a, _, _| {
+        *a += 1;
+        calls += 1;
+    });
+
+    assert_eq!(calls, 6);
+    assert_eq!(array, [2, 2, 2]);
+}
+
+#[test]
+#[should_panic]
+fn triple_iterator_panics_with_short_slice() {
+    let mut array = [1, 2];
+    TripleIterator::new(&mut array);
+}
+
+#[test]
+fn cross_iterator_visits_every_combination_once() {
+    let mut left = [0, 1, 2];
+    let mut right = [10, 20];
+    let mut iter = CrossIterator::new(&mut left, &mut right);
+    assert_eq!(iter.len(), 6);
+
+    let pairs = iter.by_ref().map(|(l, r)| unsafe { (*l, *r) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 10), (0, 20), (1, 10), (1, 20), (2, 10), (2, 20)]);
+    assert!(iter.next().is_none());
+
+    iter.reset();
+    assert_eq!(iter.count(), 6);
+}
+
+#[test]
+fn cross_iterator_safe_for_each_mutates_both_sides() {
+    let mut left = [1, 2];
+    let mut right = [10, 20, 30];
+
+    CrossIterator::new(&mut left, &mut right).safe_for_each(|l, r| {
+        *l += 1;
+        *r += 1;
+    });
+
+    assert_eq!(left, [4, 5]);
+    assert_eq!(right, [12, 22, 32]);
+}
+
+#[test]
+fn cross_iterator_with_empty_slice() {
+    let mut left = [1, 2];
+    let mut right: [i32; 0] = [];
+
+    assert_eq!(CrossIterator::new(&mut left, &mut right).count(), 0);
+}
+
+#[test]
+fn debug_prints_the_cursors_and_the_length() {
+    struct NotDebug;
+
+    let mut array = [NotDebug, NotDebug, NotDebug];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.next();
+    assert_eq!(format!("{:?}", iter), "DoubleIterator { first: 0, second: 2, len: 3 }");
+
+    let iter = SingleLineIterator::new(&mut array, 1);
+    let printed = format!("{:?}", iter);
+    assert!(printed.contains("index: 1"));
+    assert!(printed.contains("cur: 0"));
+    assert!(printed.contains("len: 3"));
+}
+
+#[test]
+fn index_pairs_follow_the_double_iterator_schedule() {
+    let mut iter = IndexPairs::new(3);
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.by_ref().collect::<Vec<(usize, usize)>>(), vec![(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    let mut array = [0, 1, 2, 3];
+    let base = array.as_mut_ptr();
+    let pointers = DoubleIterator::new(&mut array).map(|(i, j)| unsafe { (i.offset_from(base) as usize, j.offset_from(base) as usize) });
+    assert!(pointers.eq(IndexPairs::new(4)));
+    assert_eq!(iter.count(), 6);
+
+    assert_eq!(IndexPairs::new(1).count(), 0);
+}
+
+#[test]
+fn index_pairs_combinations() {
+    let mut iter = IndexPairs::new_combinations(4);
+    assert!(iter.is_combinations());
+    assert_eq!(iter.len(), 6);
+
+    iter.next();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.by_ref().collect::<Vec<(usize, usize)>>(), vec![(0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+
+    iter.reset();
+    assert_eq!(iter.next(), Some((0, 1)));
+}
+
+#[test]
+fn double_iterator_nth_matches_next() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut combinations = [0, 1, 2, 3, 4];
+    let total = DoubleIterator::new(&mut array).len();
+
+    for k in 0..=total {
+        let mut jumping = DoubleIterator::new(&mut array);
+        let jumped = jumping.nth(k);
+        let rest = jumping.len();
+
+        let mut walking = DoubleIterator::new(&mut array);
+        let mut walked = None;
+        for _ in 0..=k {
+            walked = walking.next();
+        }
+
+        assert_eq!(jumped, walked);
+        assert_eq!(rest, walking.len());
+    }
+
+    let mut iter = DoubleIterator::new_combinations(&mut combinations);
+    iter.next();
+    let base = iter.nth(2).unwrap();
+    assert_eq!(unsafe { (*base.0, *base.1) }, (0, 4));
+    assert_eq!(iter.nth(5).map(|(i, j)| unsafe { (*i, *j) }), Some((3, 4)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn owned_double_iterator_gives_back_the_mutated_vec() {
+    let mut iter = OwnedDoubleIterator::new(vec![1, 2, 3]);
+    assert_eq!(iter.len(), 6);
+
+    let (i, j) = iter.next().unwrap();
+    unsafe {
+        *i += *j;
+    }
+
+    let values = iter.into_inner();
+    assert_eq!(values, vec![3, 2, 3]);
+
+    let mut iter = OwnedDoubleIterator::new(values);
+    iter.by_ref().for_each(drop);
+    iter.reset();
+    assert_eq!(iter.safe_for_each(|i, _| *i *= 2), vec![12, 8, 12]);
+}
+
+#[test]
+fn double_iterator_peek_doesnt_advance() {
+    let mut array = [1, 2, 3, 4];
+    let base = array.as_mut_ptr();
+    let mut iter = DoubleIterator::with_stride(&mut array, 2);
+
+    while let Some((first, second)) = iter.peek_indices() {
+        let peeked = iter.peek();
+        let (i, j) = iter.next().unwrap();
+
+        assert_eq!(peeked, Some((i, j)));
+        assert_eq!(unsafe { (base.add(first), base.add(second)) }, (i, j));
+    }
+
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    iter.next_back();
+    assert_eq!(iter.peek_indices(), Some((0, 2)));
+}
+
+
+// Regression test: no lookahead must survive a reset
+#[test]
+fn reset_discards_lookahead() {
+    let mut array = [1, 2, 3, 4];
+    let base = array.as_mut_ptr();
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.nth(4);
+    assert_eq!(iter.peek_indices(), Some((1, 3)));
+    assert!(iter.peek().is_some());
+
+    iter.reset();
+    assert_eq!(iter.peek_indices(), Some((0, 1)));
+    assert_eq!(iter.next(), Some(unsafe { (base, base.add(1)) }));
+
+    // Same after a peek on an exhausted iterator
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.peek(), None);
+    iter.reset();
+    assert_eq!(iter.next(), Some(unsafe { (base, base.add(1)) }));
+
+    let mut batches = DoubleIterator::new(&mut array).batched(5);
+    batches.next();
+    batches.reset();
+    assert_eq!(batches.next().unwrap()[0], (0, 1));
+
+    let mut shard = DoubleIterator::new(&mut array).shard(3, 1);
+    shard.next();
+    shard.reset();
+    assert_eq!(shard.next(), Some(unsafe { (base, base.add(2)) }));
+}
+#[test]
+fn adjacent_pair_iterator_yields_each_neighbour_pair() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = AdjacentPairIterator::new(&mut array);
+    assert_eq!(iter.len(), 3);
+
+    let values = iter.by_ref().map(|(a, b)| unsafe { (*a, *b) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(values, vec![(1, 2), (2, 3), (3, 4)]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    iter.next();
+    iter.safe_for_each(std::mem::swap);
+    assert_eq!(array, [1, 3, 4, 2]);
+}
+
+#[test]
+#[should_panic]
+fn adjacent_pair_iterator_panics_on_short_slices() {
+    let mut array = [1];
+    AdjacentPairIterator::new(&mut array);
+}
+
+#[test]
+fn block_double_iterator_yields_the_same_pairs_as_double_iterator() {
+    for len in 2..8 {
+        for block_size in 1..(len + 2) {
+            let mut array = (0..len).collect::<Vec<usize>>();
+
+            let mut expected = DoubleIterator::new(&mut array).map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(usize, usize)>>();
+            let mut iter = BlockDoubleIterator::new(&mut array, block_size);
+            assert_eq!(iter.len(), expected.len());
+
+            let mut pairs = iter.by_ref().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(usize, usize)>>();
+            assert_eq!(iter.next(), None);
+
+            pairs.sort();
+            expected.sort();
+            assert_eq!(pairs, expected);
+        }
+    }
+}
+
+#[test]
+fn block_double_iterator_goes_block_by_block() {
+    let mut array = [0, 1, 2];
+    let mut iter = BlockDoubleIterator::new(&mut array, 2);
+
+    let pairs = iter.by_ref().map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 1), (1, 0), (0, 2), (1, 2), (2, 0), (2, 1)]);
+
+    iter.reset();
+    assert_eq!(iter.len(), 6);
+    let mut count = 0;
+    iter.safe_for_each(|i, j| {
+        assert_ne!(i, j);
+        count += 1;
+    });
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn filter_pairs_keeps_the_pairs_satisfying_the_predicate() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array).filter_pairs(|a, b| (a + b) % 2 == 0);
+
+    let mut kept = Vec::new();
+    while let Some((a, b)) = iter.next() {
+        kept.push((*a, *b));
+    }
+    assert_eq!(kept, vec![(1, 3), (1, 5), (2, 4), (3, 1), (3, 5), (4, 2), (5, 1), (5, 3)]);
+    assert!(iter.next().is_none());
+
+    iter.reset();
+    iter.safe_for_each(|a, _| *a += 2);
+    assert_eq!(array, [5, 4, 7, 6, 9]);
+}
+
+#[test]
+fn map_pairs_yields_the_values_computed_from_each_pair() {
+    let mut array = [1, 2, 3];
+    let products = DoubleIterator::new(&mut array).map_pairs(|a, b| a * b).collect::<Vec<i32>>();
+
+    assert_eq!(products, vec![2, 3, 2, 6, 3, 6]);
+}
+
+#[test]
+fn safe_fold_threads_the_accumulator() {
+    let mut array = [1, 2, 3, 4];
+    let mut pairs = DoubleIterator::new_combinations(&mut array);
+    pairs.next();
+    assert_eq!(pairs.safe_fold(0, |sum, i, j| sum + *i * *j), 33);
+
+    let (count, sum) = SingleLineIterator::new(&mut array, 3).safe_fold((0, 0), |(count, sum), fixed, other| {
+        *fixed += 1;
+        (count + 1, sum + *fixed * *other)
+    });
+    assert_eq!((count, sum), (3, 38));
+    assert_eq!(array, [1, 2, 3, 7]);
+}
+
+#[test]
+fn upper_triangle_iterator_covers_the_strict_upper_triangle() {
+    let mut array = [0; 5];
+    let base = array.as_mut_ptr();
+    let mut iter = UpperTriangleIterator::new(&mut array);
+    assert_eq!(iter.len(), 10);
+
+    let mut count = 0;
+    for ((i, j), (a, b)) in iter.by_ref() {
+        assert!(i < j);
+        assert_eq!(unsafe { (base.add(i), base.add(j)) }, (a, b));
+        count += 1;
+    }
+    assert_eq!(count, 5 * 4 / 2);
+
+    iter.reset();
+    iter.safe_for_each(|i, j, a, b| {
+        *a += 1;
+        *b += i * j;
+    });
+    assert_eq!(array, [4, 3, 4, 10, 24]);
+}
+
+#[test]
+fn single_line_par_safe_for_each_matches_the_sequential_result() {
+    let mut sequential = (0..100).collect::<Vec<u64>>();
+    let mut parallel = sequential.clone();
+
+    let mut iter = SingleLineIterator::with_stride(&mut sequential, 10, 3);
+    iter.next();
+    iter.safe_for_each(|fixed, other| *other = *other * *other + *fixed);
+
+    let mut iter = SingleLineIterator::with_stride(&mut parallel, 10, 3);
+    iter.next();
+    iter.par_safe_for_each(|fixed, other| *other = *other * *other + *fixed);
+
+    assert_eq!(sequential, parallel);
+    assert_eq!(parallel[10], 10);
+    assert_eq!(parallel[0], 0);
+    assert_eq!(parallel[3], 19);
+}
+
+#[test]
+fn diagonal_pair_iterator_yields_one_diagonal() {
+    let mut array = [0, 1, 2, 3, 4];
+    let mut iter = DiagonalPairIterator::new(&mut array, 2);
+    assert_eq!(iter.len(), 3);
+
+    let pairs = iter.by_ref().map(|(a, b)| unsafe { (*a, *b) }).collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(0, 2), (1, 3), (2, 4)]);
+    assert_eq!(iter.next(), None);
+
+    iter.reset();
+    iter.safe_for_each(|a, b| *b += *a);
+    assert_eq!(array, [0, 1, 2, 4, 6]);
+}
+
+#[test]
+#[should_panic]
+fn diagonal_pair_iterator_panics_on_a_too_big_offset() {
+    let mut array = [0, 1, 2];
+    DiagonalPairIterator::new(&mut array, 3);
+}
+
+#[test]
+fn try_set_describes_the_errors() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+
+    assert_eq!(iter.try_set(2, 2), Err(SetError::EqualIndexes(2)));
+    assert_eq!(iter.try_set(4, 0), Err(SetError::FirstOutOfRange { index: 4, len: 4 }));
+    assert_eq!(iter.try_set(0, 7), Err(SetError::SecondOutOfRange { index: 7, len: 4 }));
+    assert_eq!(iter.try_set(3, 1), Err(SetError::NotInTraversal { first: 3, second: 1 }));
+    assert_eq!(iter.position(), (0, 1));
+
+    assert_eq!(iter.try_set(1, 3), Ok(()));
+    assert_eq!(iter.position(), (1, 3));
+
+    let error: Box<dyn std::error::Error> = Box::new(SetError::FirstOutOfRange { index: 4, len: 4 });
+    assert_eq!(error.to_string(), "the first index 4 is out of range for a slice of length 4");
+}
+
+#[test]
+fn pairwise_sum_of_absolute_differences() {
+    let mut array: [i32; 3] = [1, 4, 9];
+
+    assert_eq!(DoubleIterator::new(&mut array).pairwise_sum(|a, b| (a - b).abs()), 32);
+    assert_eq!(DoubleIterator::new_combinations(&mut array).pairwise_sum(|a, b| (a - b).abs()), 16);
+}
+
+#[test]
+fn double_iterator_can_be_walked_by_mutable_reference() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    let mut passes = Vec::new();
+
+    for _ in 0..2 {
+        let mut pairs = Vec::new();
+        for (i, j) in &mut iter {
+            pairs.push(unsafe { (*i, *j) });
+        }
+
+        passes.push(pairs);
+        iter.reset();
+    }
+
+    assert_eq!(passes[0], vec![(1, 2), (1, 3), (2, 1), (2, 3), (3, 1), (3, 2)]);
+    assert_eq!(passes[0], passes[1]);
+    assert_eq!(iter.len(), 6);
+}
+
+#[test]
+fn safe_for_each_symmetric_visits_each_unordered_pair_once() {
+    let mut array = [0usize, 1, 2, 3];
+    let mut visits = [[0; 4]; 4];
+
+    DoubleIterator::new(&mut array).safe_for_each_symmetric(|a, b| {
+        visits[*a][*b] += 1;
+    });
+
+    for (i, row) in visits.iter().enumerate() {
+        for (j, &count) in row.iter().enumerate() {
+            assert_eq!(count, (i < j) as i32);
+        }
+    }
+
+    let mut count = 0;
+    DoubleIterator::with_max_distance(&mut array, 1).safe_for_each_symmetric(|_, _| count += 1);
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn safe_for_each_symmetric_walks_the_remaining_pairs_only() {
+    type Mode = fn(&mut [usize]) -> DoubleIterator<'_, usize>;
+    let modes: [Mode; 4] = [
+        |slice| DoubleIterator::new(slice),
+        |slice| DoubleIterator::new_combinations(slice),
+        |slice| DoubleIterator::with_stride(slice, 2),
+        |slice| DoubleIterator::with_max_distance(slice, 2),
+    ];
+
+    for mode in modes.iter() {
+        let mut array = [0, 1, 2, 3, 4, 5];
+        let total = mode(&mut array).count();
+
+        for skipped in 0..=total {
+            for back in 0..=(total - skipped) {
+                let mut iter = mode(&mut array);
+                iter.by_ref().take(skipped).for_each(drop);
+                iter.by_ref().rev().take(back).for_each(drop);
+
+                let expected = iter
+                    .map(|(i, j)| unsafe { (*i, *j) })
+                    .filter(|(i, j)| i < j)
+                    .collect::<Vec<(usize, usize)>>();
+
+                let mut iter = mode(&mut array);
+                iter.by_ref().take(skipped).for_each(drop);
+                iter.by_ref().rev().take(back).for_each(drop);
+
+                let mut symmetric = Vec::new();
+                iter.safe_for_each_symmetric(|i, j| symmetric.push((*i, *j)));
+                assert_eq!(symmetric, expected);
+
+                let mut iter = mode(&mut array);
+                iter.by_ref().take(skipped).for_each(drop);
+                iter.by_ref().rev().take(back).for_each(drop);
+
+                let mut relaxed = Vec::new();
+                iter.relax_pairs(|i, j| {
+                    relaxed.push((*i, *j));
+                    0.0
+                });
+                assert_eq!(relaxed, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn single_line_with_position() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = SingleLineIterator::with_position(&mut array, 2, 1);
+
+    assert_eq!(iter.position(), (2, 1));
+    let partners = iter.by_ref().map(|(_, j)| unsafe { *j }).collect::<Vec<i32>>();
+    assert_eq!(partners, vec![2, 4]);
+
+    iter.reset();
+    assert_eq!(iter.count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn single_line_with_position_rejects_a_bad_index() {
+    let mut array = [1, 2, 3];
+    SingleLineIterator::with_position(&mut array, 3, 0);
+}
+
+#[test]
+#[should_panic]
+fn single_line_with_position_rejects_a_bad_cur() {
+    let mut array = [1, 2, 3];
+    SingleLineIterator::with_position(&mut array, 0, 3);
+}
+
+#[test]
+#[should_panic]
+fn single_line_with_position_rejects_the_fixed_element() {
+    let mut array = [1, 2, 3];
+    SingleLineIterator::with_position(&mut array, 1, 1);
+}
+
+#[test]
+fn double_iterator_round_trips_through_single_line_iterator() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = DoubleIterator::new(&mut array);
+    for _ in 0..4 {
+        iter.next();
+    }
+    assert_eq!(iter.position(), (1, 2));
+
+    let mut line = SingleLineIterator::from(iter);
+    let pair = line.next().map(|(i, j)| unsafe { (*i, *j) });
+    assert_eq!(pair, Some((2, 3)));
+
+    let mut iter = DoubleIterator::from(line);
+    assert_eq!(iter.position(), (1, 3));
+    assert_eq!(iter.next().map(|(i, j)| unsafe { (*i, *j) }), Some((2, 4)));
+    assert_eq!(iter.len(), 6);
+
+    let mut array = [1, 2, 3];
+    let mut line = SingleLineIterator::new(&mut array, 1);
+    line.by_ref().for_each(drop);
+    let mut iter = DoubleIterator::from(line);
+    assert_eq!(iter.next().map(|(i, j)| unsafe { (*i, *j) }), Some((3, 1)));
+
+    let mut line = SingleLineIterator::new(&mut array, 2);
+    line.by_ref().for_each(drop);
+    assert_eq!(DoubleIterator::from(line).next(), None);
+}
+
+#[test]
+fn single_line_iterator_from_an_exhausted_double_iterator_is_empty() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.by_ref().for_each(drop);
+
+    let mut line = SingleLineIterator::from(iter);
+    assert_eq!(line.position(), (2, 3));
+    assert!(line.next().is_none());
+    assert!(line.next_back().is_none());
+    assert!(line.next().is_none());
+
+    // Crossed cursors, the front one still being inside of the slice
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.set(1, 2);
+    for _ in 0..3 {
+        assert!(iter.next_back().is_some());
+    }
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.position(), (1, 2));
+    assert!(SingleLineIterator::from(iter).next().is_none());
+}
+
+#[test]
+fn single_line_iterator_from_double_iterator_keeps_the_modes() {
+    let collect = |line: SingleLineIterator<'_, i32>| line.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+    let mut array = [0, 1, 2, 3, 4, 5, 6];
+
+    let mut iter = DoubleIterator::with_stride(&mut array, 2);
+    iter.set(2, 0);
+    assert_eq!(collect(SingleLineIterator::from(iter)), vec![(2, 0), (2, 4), (2, 6)]);
+
+    let mut iter = DoubleIterator::with_max_distance(&mut array, 2);
+    iter.set(3, 2);
+    assert_eq!(collect(SingleLineIterator::from(iter)), vec![(3, 2), (3, 4), (3, 5)]);
+
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+    iter.set(4, 5);
+    assert_eq!(collect(SingleLineIterator::from(iter)), vec![(4, 5), (4, 6)]);
+
+    // Stops at the back cursor
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.set(6, 2);
+    iter.next_back();
+    iter.next_back();
+    assert_eq!(collect(SingleLineIterator::from(iter)), vec![(6, 2), (6, 3)]);
+}
+
+#[test]
+fn double_iterator_drains_short_slices_exactly() {
+    for len in 2..4 {
+        let mut array = (0..len).collect::<Vec<usize>>();
+        let mut iter = DoubleIterator::new(&mut array);
+
+        let mut count = 0;
+        for (i, j) in iter.by_ref() {
+            assert_ne!(i, j);
+            count += 1;
+        }
+
+        assert_eq!(count, len * (len - 1));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.position(), (len, 0));
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+
+        // Meeting in the middle yields every pair once too
+        iter.reset();
+        let mut count = 0;
+        while iter.next().is_some() {
+            count += 1;
+            if iter.next_back().is_some() {
+                count += 1;
+            }
+        }
+        assert_eq!(count, len * (len - 1));
+    }
+}
+
+#[test]
+fn shards_cover_every_pair_once() {
+    let mut array = [0, 1, 2, 3, 4];
+    let expected = DoubleIterator::new(&mut array).map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+
+    for total in 1..8 {
+        let mut union = Vec::new();
+        for rank in 0..total {
+            let shard = DoubleIterator::new(&mut array).shard(total, rank);
+            let len = shard.len();
+
+            let pairs = shard.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+            assert_eq!(pairs.len(), len);
+            union.extend(pairs);
+        }
+
+        union.sort();
+        let mut sorted = expected.clone();
+        sorted.sort();
+        assert_eq!(union, sorted);
+    }
+
+    let mut shard = DoubleIterator::new(&mut array).shard(3, 2);
+    shard.by_ref().for_each(drop);
+    shard.reset();
+    assert_eq!(shard.next().map(|(i, j)| unsafe { (*i, *j) }), Some(expected[2]));
+}
+
+#[test]
+fn window_mut_iterator() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = WindowMutIterator::new(&mut array, 2);
+    assert_eq!(iter.len(), 4);
+
+    let mut windows = Vec::new();
+    while let Some(window) = iter.next() {
+        windows.push(window.to_vec());
+        window[1] *= 10;
+    }
+    assert_eq!(windows, vec![vec![1, 2], vec![20, 3], vec![30, 4], vec![40, 5]]);
+    assert!(iter.is_empty());
+    assert!(iter.next().is_none());
+
+    iter.reset();
+    assert_eq!(iter.len(), 4);
+    iter.safe_for_each(|window| window[0] += 1);
+    assert_eq!(array, [2, 21, 31, 41, 50]);
+
+    let mut single = [7, 8];
+    let mut iter = WindowMutIterator::new(&mut single, 2);
+    assert_eq!(iter.next(), Some(&mut [7, 8][..]));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+#[should_panic]
+fn window_mut_iterator_zero_width() {
+    WindowMutIterator::new(&mut [1, 2], 0);
+}
+
+#[test]
+#[should_panic]
+fn window_mut_iterator_too_wide() {
+    WindowMutIterator::new(&mut [1, 2], 3);
+}
+
+#[test]
+fn double_iterator_source() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    iter.next();
+    assert_eq!(iter.source(), &[1, 2, 3]);
+
+    iter.source_mut()[2] = 30;
+    let (i, j) = iter.next().unwrap();
+    unsafe {
+        assert_eq!((*i, *j), (1, 30));
+    }
+
+    assert_eq!(iter.position(), (1, 0));
+    iter.source_mut().swap(0, 1);
+    assert_eq!(iter.source(), &[2, 1, 30]);
+}
+
+#[test]
+fn single_line_iterator_source() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = SingleLineIterator::new(&mut array, 0);
+
+    iter.next();
+    iter.source_mut()[3] = 40;
+    assert_eq!(iter.source(), &[1, 2, 3, 40]);
+
+    let partners = iter.map(|(_, x)| unsafe { *x }).collect::<Vec<i32>>();
+    assert_eq!(partners, vec![3, 40]);
+}
+
+#[test]
+fn collect_index_pairs() {
+    let mut array = [1, 2, 3, 4];
+    let iter = DoubleIterator::new(&mut array);
+
+    assert_eq!(iter.collect_index_pairs(), vec![
+        (0, 1), (0, 2), (0, 3),
+        (1, 0), (1, 2), (1, 3),
+        (2, 0), (2, 1), (2, 3),
+        (3, 0), (3, 1), (3, 2),
+    ]);
+
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.nth(9);
+    assert_eq!(iter.collect_index_pairs(), vec![(3, 1), (3, 2)]);
+
+    let iter = DoubleIterator::with_max_distance(&mut array, 1);
+    assert_eq!(iter.collect_index_pairs(), vec![(0, 1), (1, 0), (1, 2), (2, 1), (2, 3), (3, 2)]);
+}
+
+#[test]
+fn pair_by_key() {
+    let mut array: [i32; 4] = [3, 1, 7, 2];
+
+    let iter = DoubleIterator::new_combinations(&mut array);
+    assert_eq!(iter.max_pair_by_key(|a, b| (a - b).abs()), Some((1, 2)));
+
+    // The last of the two pairs at distance 6 in the full traversal
+    let iter = DoubleIterator::new(&mut array);
+    assert_eq!(iter.max_pair_by_key(|a, b| (a - b).abs()), Some((2, 1)));
+
+    // The first of the pairs at distance 1
+    let iter = DoubleIterator::new(&mut array);
+    assert_eq!(iter.min_pair_by_key(|a, b| (a - b).abs()), Some((0, 3)));
+
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.max_pair_by_key(|a, b| a + b), None);
+    assert_eq!(array, [3, 1, 7, 2]);
+}
+
+#[test]
+fn find_pair() {
+    let mut array = [1, 4, 2, 6, 3];
+
+    let mut calls = 0;
+    let iter = DoubleIterator::new(&mut array);
+    let found = iter.find_pair(|a, b| {
+        calls += 1;
+        a + b == 5
+    });
+    assert_eq!(found, Some((0, 1)));
+    assert_eq!(calls, 1);
+
+    let mut calls = 0;
+    let iter = DoubleIterator::new_combinations(&mut array);
+    let found = iter.find_pair(|a, b| {
+        calls += 1;
+        a + b == 8
+    });
+    // (0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4), (2, 3)
+    assert_eq!(found, Some((2, 3)));
+    assert_eq!(calls, 8);
+
+    // The search starts at the current position
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.next();
+    assert_eq!(iter.find_pair(|a, b| a + b == 5), Some((1, 0)));
+
+    let iter = DoubleIterator::new(&mut array);
+    assert_eq!(iter.find_pair(|a, b| a + b == 100), None);
+}
+
+#[test]
+fn disjoint_pair_iterator() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DisjointPairIterator::new(&mut array);
+    assert_eq!(iter.len(), 2);
+
+    let (a, b) = iter.next().unwrap();
+    *a += 10;
+    *b += 20;
+
+    let (c, d) = iter.next().unwrap();
+    assert_eq!((*c, *d), (3, 4));
+    *c = 0;
+
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+    assert_eq!(array, [11, 22, 0, 4, 5]);
+
+    let mut empty: [i32; 1] = [1];
+    assert_eq!(DisjointPairIterator::new(&mut empty).count(), 0);
+}
+
+#[test]
+fn safe_for_each_with() {
+    use std::collections::HashMap;
+
+    let mut array = [1, 2, 3];
+    let mut interactions = HashMap::new();
+
+    DoubleIterator::new(&mut array).safe_for_each_with(&mut interactions, |interactions, i, j| {
+        *interactions.entry(*i).or_insert(0) += *j;
+    });
+
+    // The sum of the partners of each member
+    assert_eq!(interactions.len(), 3);
+    assert_eq!(interactions[&1], 5);
+    assert_eq!(interactions[&2], 4);
+    assert_eq!(interactions[&3], 3);
+
+    // The state is still the caller's
+    interactions.clear();
+    DoubleIterator::new_combinations(&mut array).safe_for_each_with(&mut interactions, |interactions, i, j| {
+        interactions.insert(*i * 10 + *j, 1);
+        *j += 1;
+    });
+    assert_eq!(interactions.len(), 3);
+    assert_eq!(array, [1, 3, 5]);
+}
+
+#[test]
+fn safe_for_each_enumerated() {
+    for len in 2..6 {
+        let mut array = vec![0; len];
+        let mut ordinals = Vec::new();
+
+        DoubleIterator::new(&mut array).safe_for_each_enumerated(|ordinal, i, _j| {
+            ordinals.push(ordinal);
+            *i += 1;
+        });
+
+        // Contiguous over the n * (n - 1) pairs
+        assert_eq!(ordinals, (0..len * (len - 1)).collect::<Vec<usize>>());
+        assert!(array.iter().all(|&x| x == len - 1));
+    }
+
+    // Counted from the current position
+    let mut array = [0, 1, 2];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.next();
+    iter.next();
+
+    let mut ordinals = Vec::new();
+    iter.safe_for_each_enumerated(|ordinal, _i, _j| ordinals.push(ordinal));
+    assert_eq!(ordinals, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn safe_for_each_checked() {
+    use crate::share::TooShortError;
+
+    let mut array = [1, 2, 3, 4];
+    let mut sum = 0;
+    assert_eq!(DoubleIterator::safe_for_each_checked(&mut array, |i, j| sum += *i * *j), Ok(12));
+    assert_eq!(sum, 2 * (2 + 3 + 4 + 6 + 8 + 12));
+
+    // Never panics on short slices, and never calls the closure
+    let mut single = [1];
+    assert_eq!(DoubleIterator::safe_for_each_checked(&mut single, |_, _| panic!()), Err(TooShortError { len: 1 }));
+
+    let mut empty: [i32; 0] = [];
+    assert_eq!(DoubleIterator::safe_for_each_checked(&mut empty, |_, _| panic!()), Err(TooShortError { len: 0 }));
+}
+
+#[test]
+fn assert_symmetric_accepts_symmetric_functions() {
+    let array = [3, 1, 4, 1, 5];
+    DoubleIterator::assert_symmetric(&array, |a: &i32, b: &i32| a * b);
+    DoubleIterator::assert_symmetric(&array, |a: &i32, b: &i32| (a - b).abs());
+
+    // Nothing to check
+    DoubleIterator::assert_symmetric(&[1], |_: &i32, _: &i32| panic!());
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn assert_symmetric_does_nothing_in_release_builds() {
+    DoubleIterator::assert_symmetric(&[1, 2, 3], |_: &i32, _: &i32| -> bool { panic!() });
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not symmetric for the pair of indexes (0, 2)")]
+fn assert_symmetric_reports_the_indexes() {
+    // Symmetric for (0, 1) since both members are equal, so (0, 2) is the first failure
+    let array = [2, 2, 7];
+    DoubleIterator::assert_symmetric(&array, |a: &i32, b: &i32| a - b);
+}
+
+// The `exhausted` flag keeps `next` away from the indexes once the traversal is over. The checks below don't rely on the `debug_assert!`s, so they hold in release builds too
+#[test]
+fn double_iterator_stays_exhausted() {
+    for len in 2..4 {
+        let mut array = vec![0; len];
+        let mut iter = DoubleIterator::new(&mut array);
+
+        assert_eq!(iter.by_ref().count(), len * (len - 1));
+        assert!(iter.exhausted);
+
+        for _ in 0..100 {
+            assert!(iter.next().is_none());
+        }
+        assert_eq!(iter.position(), (len, 0));
+        assert_eq!(iter.len(), 0);
+
+        // Cleared when the cursor is moved back into the traversal
+        iter.reset();
+        assert!(!iter.exhausted);
+        assert_eq!(iter.by_ref().count(), len * (len - 1));
+
+        iter.set(1, 0);
+        assert!(!iter.exhausted);
+        assert!(iter.next().is_some());
+    }
+
+    // Exhausted from the back or by jumping past the end
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+    while iter.next_back().is_some() {}
+    assert!(iter.exhausted);
+    assert!(iter.next().is_none());
+
+    let mut iter = DoubleIterator::new(&mut array);
+    assert!(iter.nth(6).is_none());
+    assert!(iter.exhausted);
+    assert!(iter.next().is_none());
+
+    // Kept by the conversion into a `SingleLineIterator`, whatever the indexes are
+    for len in 2..4 {
+        let mut array = vec![0; len];
+        let mut iter = DoubleIterator::new(&mut array);
+        iter.by_ref().for_each(drop);
+        assert!(iter.exhausted);
+
+        let mut line = SingleLineIterator::from(iter);
+        for _ in 0..100 {
+            assert!(line.next().is_none());
+        }
+        assert!(line.next_back().is_none());
+    }
+
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.exhausted = true;
+    assert!(SingleLineIterator::from(iter).next().is_none());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "second index 5 is out of a slice of length 3")]
+fn double_iterator_checks_second_index() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::with_raw_position(&mut array, 0, 5);
+    iter.next();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "both indexes are equal")]
+fn double_iterator_checks_equal_indexes() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::with_raw_position(&mut array, 1, 1);
+    iter.next();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "first index 4 is out of a slice of length 3")]
+fn double_iterator_checks_first_index() {
+    let mut array = [1, 2, 3];
+    // `next` sees this state as exhausted, so the check is called directly
+    let iter = DoubleIterator::with_raw_position(&mut array, 4, 0);
+    iter.debug_check_position();
+}
+
+#[test]
+fn double_iterator_checks_pass_on_valid_states() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = DoubleIterator::with_raw_position(&mut array, 2, 3);
+    assert_eq!(iter.by_ref().count(), 4);
+
+    iter.set(0, 3);
+    assert_eq!(iter.count(), 10);
+}
+
+#[test]
+fn zip_results() {
+    let mut array = [1, 2, 3];
+    let mut products = [0; 6];
+
+    DoubleIterator::new(&mut array).zip_results(&mut products).safe_for_each(|i, j, product| *product = *i * *j);
+    assert_eq!(products, [2, 3, 2, 6, 3, 6]);
+
+    // The cells follow the position in the full traversal, even when starting later
+    let mut sums = [0; 6];
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.nth(3);
+    let mut zipped = iter.zip_results(&mut sums);
+    while let Some((i, j, sum)) = zipped.next() {
+        *sum = *i + *j;
+        *i += 1;
+    }
+    assert_eq!(sums, [0, 0, 0, 0, 4, 6]);
+    assert_eq!(array, [1, 2, 5]);
+}
+
+#[test]
+#[should_panic]
+fn zip_results_too_short() {
+    let mut array = [1, 2, 3];
+    let mut products = [0; 5];
+
+    DoubleIterator::new(&mut array).zip_results(&mut products);
+}
+
+#[test]
+fn double_iterator_builder() {
+    let mut array = [0, 1, 2, 3, 4, 5];
+
+    let iter = DoubleIterator::builder(&mut array).build().unwrap();
+    assert_eq!(iter.count(), 30);
+
+    let iter = DoubleIterator::builder(&mut array).combinations(true).max_distance(2).build().unwrap();
+    assert_eq!(iter.collect_index_pairs(), vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4), (3, 5), (4, 5)]);
+
+    let iter = DoubleIterator::builder(&mut array).stride(2).max_distance(2).start_at(2, 0).build().unwrap();
+    assert_eq!(iter.collect_index_pairs(), vec![(2, 0), (2, 4), (4, 2)]);
+
+    // The combined modes are kept by `reset`
+    let mut iter = DoubleIterator::builder(&mut array).combinations(true).stride(2).build().unwrap();
+    iter.by_ref().for_each(drop);
+    iter.reset();
+    assert_eq!(iter.len(), 3);
+}
+
+#[test]
+fn double_iterator_builder_errors() {
+    let mut array = [0, 1, 2, 3, 4];
+
+    assert_eq!(DoubleIterator::builder(&mut array).stride(0).build().err(), Some(BuildError::ZeroStride));
+    assert_eq!(DoubleIterator::builder(&mut array).stride(5).build().err(), Some(BuildError::StrideTooLarge { step: 5, len: 5 }));
+    assert_eq!(DoubleIterator::builder(&mut array).max_distance(0).build().err(), Some(BuildError::ZeroDistance));
+    assert_eq!(
+        DoubleIterator::builder(&mut array).stride(2).max_distance(1).build().err(),
+        Some(BuildError::DistanceShorterThanStride { max_distance: 1, step: 2 }),
+    );
+    assert_eq!(
+        DoubleIterator::builder(&mut array).combinations(true).start_at(3, 1).build().err(),
+        Some(BuildError::BadStart(SetError::NotInTraversal { first: 3, second: 1 })),
+    );
+
+    let mut short = [0];
+    let error = DoubleIterator::builder(&mut short).build().err().unwrap();
+    assert_eq!(error, BuildError::TooShort(TooShortError { len: 1 }));
+    assert_eq!(error.to_string(), TooShortError { len: 1 }.to_string());
+}
+
+#[test]
+fn remaining_index_pairs() {
+    let mut array = [1, 2, 3, 4];
+    let all = DoubleIterator::new(&mut array).collect_index_pairs();
+
+    let mut iter = DoubleIterator::new(&mut array);
+    iter.next();
+    iter.next();
+    assert_eq!(iter.remaining_index_pairs().collect::<Vec<(usize, usize)>>(), all[2..]);
+    assert_eq!(iter.remaining_index_pairs().count(), iter.len());
+
+    // Nothing is consumed
+    assert_eq!(iter.position(), (0, 3));
+
+    iter.next_back();
+    assert_eq!(iter.remaining_index_pairs().collect::<Vec<(usize, usize)>>(), all[2..11]);
+
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.remaining_index_pairs().count(), 0);
+
+    let mut iter = DoubleIterator::with_stride(&mut array, 2);
+    iter.next();
+    assert_eq!(iter.remaining_index_pairs().collect::<Vec<(usize, usize)>>(), vec![(2, 0)]);
+}
+
+#[test]
+fn remaining_index_pairs_follow_next_in_every_mode() {
+    let mut array = [0; 7];
+
+    type Mode = fn(&mut [i32]) -> DoubleIterator<'_, i32>;
+    let modes: [Mode; 5] = [
+        |slice| DoubleIterator::new(slice),
+        |slice| DoubleIterator::new_combinations(slice),
+        |slice| DoubleIterator::with_stride(slice, 3),
+        |slice| DoubleIterator::with_max_distance(slice, 2),
+        |slice| DoubleIterator::builder(slice).combinations(true).stride(2).build().unwrap(),
+    ];
+
+    for mode in modes.iter() {
+        let all = mode(&mut array).collect_index_pairs();
+
+        for skipped in 0..all.len() {
+            let mut iter = mode(&mut array);
+            iter.by_ref().take(skipped).for_each(drop);
+            assert_eq!(iter.remaining_index_pairs().collect::<Vec<(usize, usize)>>(), all[skipped..]);
+
+            iter.next_back();
+            assert_eq!(iter.remaining_index_pairs().collect::<Vec<(usize, usize)>>(), all[skipped..all.len() - 1]);
+        }
+    }
+}
+
+#[test]
+fn inspect_pairs() {
+    let mut array = [1, 2, 3, 4];
+    let expected = DoubleIterator::new(&mut array).map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+
+    let mut calls = 0;
+    let mut seen = Vec::new();
+    let pairs = DoubleIterator::new(&mut array)
+        .inspect_pairs(|a, b| {
+            calls += 1;
+            seen.push((*a, *b));
+        })
+        .map(|(i, j)| unsafe { (*i, *j) })
+        .collect::<Vec<(i32, i32)>>();
+
+    assert_eq!(calls, 12);
+    assert_eq!(pairs, expected);
+    assert_eq!(seen, expected);
+}
+
+#[test]
+fn by_rows() {
+    let mut array = [0, 1, 2, 3];
+    let mut rows = DoubleIterator::new(&mut array).by_rows();
+
+    let mut count = 0;
+    while let Some(row) = rows.next() {
+        let (index, _) = row.position();
+        let partners = row.map(|(i, j)| unsafe { (*i, *j) }).collect::<Vec<(i32, i32)>>();
+
+        let expected = (0..4).filter(|&j| j != index as i32).map(|j| (index as i32, j)).collect::<Vec<(i32, i32)>>();
+        assert_eq!(partners, expected);
+        count += 1;
+    }
+    assert_eq!(count, 4);
+    assert!(rows.next().is_none());
+
+    // The first row starts at the current position
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+    iter.next();
+    let mut rows = iter.by_rows();
+    let mut pairs = Vec::new();
+    while let Some(row) = rows.next() {
+        row.safe_for_each(|i, j| pairs.push((*i, *j)));
+    }
+    assert_eq!(pairs, vec![(0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+}
+
+#[test]
+#[should_panic]
+fn by_rows_with_stride() {
+    let mut array = [0, 1, 2, 3];
+    DoubleIterator::with_stride(&mut array, 2).by_rows();
+}
+
+#[test]
+fn grouped_double_for_each() {
+    let mut groups = vec![vec![1], vec![10, 20], vec![100, 200, 300]];
+    let mut calls = 0;
+
+    crate::share::grouped_double_for_each(&mut groups, |i, j| {
+        calls += 1;
+        // Only the members of a same group meet
+        assert_eq!(i.to_string().len(), j.to_string().len());
+        *j += 1;
+    });
+
+    assert_eq!(calls, 8);
+    assert_eq!(groups, vec![vec![1], vec![11, 21], vec![102, 202, 302]]);
+
+    let mut empty: Vec<Vec<i32>> = Vec::new();
+    crate::share::grouped_double_for_each(&mut empty, |_, _| panic!());
+}
+
+#[test]
+fn double_over_tail() {
+    let mut array = [100, 1, 2, 3];
+    let (origin, iter) = crate::share::double_over_tail(&mut array);
+
+    let mut pairs = 0;
+    iter.safe_for_each(|i, j| {
+        // The origin is readable while the tail is mutated
+        assert_eq!(*origin, 100);
+        *i += *j + *origin;
+        pairs += 1;
+    });
+
+    assert_eq!(pairs, 6);
+    assert_eq!(array[0], 100);
+    assert!(array[1..].iter().all(|&x| x > 200));
+}
+
+#[test]
+#[should_panic]
+fn double_over_tail_too_short() {
+    let mut array = [1, 2];
+    let _ = crate::share::double_over_tail(&mut array);
+}
+
+#[test]
+fn split_pair_gives_distinct_references() {
+    let mut array = [0, 1, 2, 3];
+
+    for (i, j) in [(0, 3), (3, 0), (1, 2), (2, 1)] {
+        let base = array.as_ptr();
+        let (a, b) = split_pair(&mut array, i, j);
+
+        assert_eq!((*a, *b), (i as i32, j as i32));
+        assert!(std::ptr::eq(a, unsafe { base.add(i) }));
+        assert!(std::ptr::eq(b, unsafe { base.add(j) }));
+    }
+}
+
+#[test]
+#[should_panic]
+fn split_pair_same_index() {
+    let mut array = [0, 1];
+    split_pair(&mut array, 1, 1);
+}
+
+#[test]
+fn safe_for_each_never_aliases() {
+    let mut array = [0u8; 5];
+    let mut visited = Vec::new();
+
+    DoubleIterator::new(&mut array).safe_for_each(|i, j| {
+        assert!(!std::ptr::eq(i, j));
+        // Both references are usable at the same time
+        *i += 1;
+        *j += 1;
+        visited.push((*i, *j));
+    });
+
+    assert_eq!(visited.len(), 20);
+    assert_eq!(array, [8; 5]);
+}
+
+#[test]
+fn cloned_pairs() {
+    let mut array = [1, 2, 3];
+    let pairs = DoubleIterator::new(&mut array).cloned_pairs().collect::<Vec<(i32, i32)>>();
+    assert_eq!(pairs, vec![(1, 2), (1, 3), (2, 1), (2, 3), (3, 1), (3, 2)]);
+
+    let mut names = [String::from("a"), String::from("b")];
+    let joined = DoubleIterator::new(&mut names).cloned_pairs().map(|(a, b)| a + &b).collect::<Vec<String>>();
+    assert_eq!(joined, vec!["ab", "ba"]);
+    assert_eq!(names, [String::from("a"), String::from("b")]);
+}
+
+#[test]
+fn diagonal_iterator() {
+    let mut array = [0; 4];
+    let mut iter = DiagonalIterator::new(&mut array);
+    assert_eq!(iter.len(), 4);
+
+    *iter.next().unwrap() += 1;
+    assert_eq!(iter.len(), 3);
+    iter.safe_for_each(|x| *x += 1);
+    assert_eq!(array, [1; 4]);
+
+    // Every index is visited once, by reference
+    let mut array = [10, 20, 30];
+    let visited = DiagonalIterator::new(&mut array).map(|x| *x).collect::<Vec<i32>>();
+    assert_eq!(visited, vec![10, 20, 30]);
+
+    let mut empty: [i32; 0] = [];
+    assert!(DiagonalIterator::new(&mut empty).next().is_none());
+}
+
+#[test]
+fn double_iterator_rebind() {
+    let mut first = [1, 2, 3];
+    let mut second = [10, 20, 30, 40];
+
+    let mut iter = DoubleIterator::new(&mut first);
+    iter.next();
+    iter.next_back();
+
+    iter.rebind(&mut second);
+    assert_eq!(iter.len(), 12);
+    let (i, j) = iter.next().unwrap();
+    unsafe {
+        assert_eq!((*i, *j), (10, 20));
+    }
+
+    let mut short = [7, 8];
+    iter.rebind(&mut short);
+    assert_eq!(iter.cloned_pairs().collect::<Vec<(i32, i32)>>(), vec![(7, 8), (8, 7)]);
+}
+
+#[test]
+#[should_panic]
+fn double_iterator_rebind_too_short() {
+    let mut first = [1, 2, 3];
+    let mut second = [1];
+
+    let mut iter = DoubleIterator::new(&mut first);
+    iter.rebind(&mut second);
+}
+
+#[test]
+fn double_iterator_progress() {
+    let mut array = [1, 2, 3, 4, 5];
+    let mut iter = DoubleIterator::new(&mut array);
+    assert_eq!(iter.progress(), 0.0);
+
+    iter.by_ref().take(10).for_each(drop);
+    assert!((iter.progress() - 0.5).abs() < 1e-9);
+
+    iter.next_back();
+    assert!((iter.progress() - 0.55).abs() < 1e-9);
+
+    iter.by_ref().for_each(drop);
+    assert_eq!(iter.progress(), 1.0);
+
+    iter.reset();
+    assert_eq!(iter.progress(), 0.0);
+
+    let mut iter = DoubleIterator::with_stride(&mut array, 2);
+    iter.by_ref().take(3).for_each(drop);
+    assert!((iter.progress() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn accumulate_per_element() {
+    let mut array = [1, 2, 3, 4];
+
+    // Each pair adds the product to both members
+    let totals = DoubleIterator::new_combinations(&mut array).accumulate_per_element(|a, b| (a * b, a * b));
+    assert_eq!(totals, vec![2 + 3 + 4, 2 + 6 + 8, 3 + 6 + 12, 4 + 8 + 12]);
+
+    // The full traversal visits each unordered pair twice
+    let totals = DoubleIterator::new(&mut array).accumulate_per_element(|a, b| (a * b, 0));
+    assert_eq!(totals, vec![9, 16, 21, 24]);
+
+    let counts = DoubleIterator::new(&mut array).accumulate_per_element(|_, _| (1usize, 1usize));
+    assert_eq!(counts, vec![6; 4]);
+}
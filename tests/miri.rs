@@ -0,0 +1,131 @@
+//! Exercises the unsafe pointer paths of the share module. Meant to be run with `cargo miri test --test miri` to check them against the stacked borrows model, but it runs as a regular test too
+
+use iterators_collection::share::{DoubleIterator, LendingDoubleIterator, SingleLineIterator};
+use iterators_collection::ResettableIterator;
+
+#[test]
+fn double_iterator_pointers_of_a_pair_together() {
+    let mut array = [1, 2, 3, 4];
+
+    for (i, j) in DoubleIterator::new(&mut array) {
+        // Both pointers are written while they are both alive
+        unsafe {
+            *i += 1;
+            *j += *i;
+        }
+    }
+
+    assert_ne!(array, [1, 2, 3, 4]);
+}
+
+#[test]
+fn double_iterator_both_ends() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    while let Some((i, j)) = iter.next() {
+        unsafe {
+            *i += *j;
+            *j *= 2;
+        }
+
+        if let Some((i, j)) = iter.next_back() {
+            unsafe {
+                *i -= *j;
+            }
+        }
+    }
+
+    iter.reset();
+    assert_eq!(iter.count(), 6);
+}
+
+#[test]
+fn double_iterator_safe_paths() {
+    let mut array: [i32; 5] = [1, 2, 3, 4, 5];
+
+    DoubleIterator::new(&mut array).safe_for_each(|i, j| *i += *j);
+    DoubleIterator::new_combinations(&mut array).safe_for_each(std::mem::swap);
+
+    let mut iter = LendingDoubleIterator::new(&mut array);
+    while let Some((i, j)) = iter.next() {
+        *j = i.wrapping_mul(*j);
+    }
+
+    let mut rows = DoubleIterator::new(&mut array).by_rows();
+    while let Some(row) = rows.next() {
+        row.safe_for_each(|i, j| *j = j.wrapping_sub(*i));
+    }
+}
+
+#[test]
+fn double_iterator_peek_and_nth() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = DoubleIterator::new(&mut array);
+
+    let (i, j) = iter.peek().unwrap();
+    unsafe {
+        *i += *j;
+    }
+
+    let (i, j) = iter.nth(5).unwrap();
+    unsafe {
+        *j += *i;
+    }
+}
+
+#[test]
+fn single_line_iterator_pointers() {
+    let mut array = [1, 2, 3, 4];
+    let mut iter = SingleLineIterator::new(&mut array, 1);
+
+    let (fixed, partner) = iter.next().unwrap();
+    unsafe {
+        *partner += *fixed;
+        *fixed += 1;
+    }
+
+    let (fixed, partner) = iter.next_back().unwrap();
+    unsafe {
+        *partner += *fixed;
+    }
+
+    SingleLineIterator::new(&mut array, 2).safe_for_each(|fixed, partner| *fixed += *partner);
+}
+
+#[test]
+fn conversion_between_iterators() {
+    let mut array = [1, 2, 3, 4];
+    let mut line = SingleLineIterator::new(&mut array, 0);
+
+    let (fixed, partner) = line.next().unwrap();
+    unsafe {
+        *partner += *fixed;
+    }
+
+    let iter = DoubleIterator::from(line);
+    iter.safe_for_each(|i, j| *i += *j);
+    // The pair (0, 1) is not yielded again
+    assert_eq!(array[0], 1 + 3 + 4);
+}
+
+#[test]
+fn single_line_iterator_from_exhausted_double_iterator() {
+    let mut array = [1, 2, 3];
+    let mut iter = DoubleIterator::new_combinations(&mut array);
+    iter.by_ref().for_each(drop);
+
+    // Nothing is yielded, so no pointer beyond the slice is built
+    let mut line = SingleLineIterator::from(iter);
+    assert!(line.next().is_none());
+    assert!(line.next_back().is_none());
+
+    // Reset, the line walks the partners of the last member
+    line.reset();
+    for (i, j) in line {
+        unsafe {
+            *j += *i;
+        }
+    }
+    assert_eq!(array, [4, 5, 3]);
+}